@@ -0,0 +1,132 @@
+//! Print the rating table of profiles exported from the statistics panel.
+//!
+//! Run `cargo run --example rankings < tic-tac-yew-profiles.json`. Ratings
+//! are recomputed from the game history with the same rules as the game,
+//! so profiles and computer strategies rank exactly as they do on screen.
+
+#[path = "../src/rating.rs"]
+mod rating;
+
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::process;
+
+use serde_json::Value;
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Player labels in turn order, as winners are saved.
+const SEATS: [&str; 4] = ["X", "O", "Delta", "Psi"];
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// Rating and results of one profile or strategy.
+struct Row {
+    name: String,
+    rating: i32,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Ranking
+//////////////////////////////////////////////////////////////////////////////
+
+/// Rows of every entity that played in the exported history, best first.
+fn rank(export: &Value) -> Option<Vec<Row>> {
+    let mut rows: HashMap<String, Row> = HashMap::new();
+
+    for game in export.get("history")?.as_array()? {
+        let players: Vec<&str> = game
+            .get("players")?
+            .as_array()?
+            .iter()
+            .map(Value::as_str)
+            .collect::<Option<_>>()?;
+
+        // A strategy playing itself is neither recorded nor rated
+        if players.iter().all(|&name| name == players[0]) {
+            continue;
+        }
+
+        let winner = match game.get("winner") {
+            Some(Value::String(seat)) => {
+                Some(SEATS.iter().position(|label| label == seat)?)
+            }
+            _ => None,
+        };
+
+        let before: Vec<i32> = players
+            .iter()
+            .map(|&name| rows.get(name).map_or(rating::INITIAL, |r| r.rating))
+            .collect();
+        let after = rating::update(&before, winner);
+
+        for (index, (&name, &rating)) in players.iter().zip(&after).enumerate()
+        {
+            let row = rows.entry(name.to_string()).or_insert_with(|| Row {
+                name: name.to_string(),
+                rating,
+                wins: 0,
+                losses: 0,
+                draws: 0,
+            });
+
+            row.rating = rating;
+
+            match winner {
+                Some(seat) if seat == index => row.wins += 1,
+                Some(_) => row.losses += 1,
+                None => row.draws += 1,
+            }
+        }
+    }
+
+    let mut rows: Vec<Row> = rows.into_values().collect();
+    rows.sort_by(|a, b| b.rating.cmp(&a.rating).then(a.name.cmp(&b.name)));
+
+    Some(rows)
+}
+
+fn main() {
+    let mut input = String::new();
+
+    if let Err(error) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Could not read the export: {}", error);
+        process::exit(1);
+    }
+
+    let rows = serde_json::from_str(&input)
+        .ok()
+        .and_then(|export| rank(&export));
+    let rows = match rows {
+        Some(rows) => rows,
+        None => {
+            eprintln!("Not a profiles export from Tic-Tac-Yew");
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "{:>4}  {:<16}  {:>6}  {:>5}  {:>4}  {:>4}  {:>4}",
+        "Rank", "Player", "Rating", "Games", "W", "L", "D"
+    );
+
+    for (index, row) in rows.iter().enumerate() {
+        println!(
+            "{:>4}  {:<16}  {:>6}  {:>5}  {:>4}  {:>4}  {:>4}",
+            index + 1,
+            row.name,
+            row.rating,
+            row.wins + row.losses + row.draws,
+            row.wins,
+            row.losses,
+            row.draws,
+        );
+    }
+}
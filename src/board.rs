@@ -9,17 +9,37 @@ use super::*;
 /// Tuple of the form (column, row) used for accessing board cells.
 pub type Position = (usize, usize);
 
+/// Cell values of a marked board, indexed as `[column][row]`.
+pub type Cells = [[Option<Player>; 3]; 3];
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Every line of a 3x3 board: columns, rows, then the two diagonals.
+pub(crate) const LINES: [[Position; 3]; 8] = [
+    [(0, 0), (0, 1), (0, 2)],
+    [(1, 0), (1, 1), (1, 2)],
+    [(2, 0), (2, 1), (2, 2)],
+    [(0, 0), (1, 0), (2, 0)],
+    [(0, 1), (1, 1), (2, 1)],
+    [(0, 2), (1, 2), (2, 2)],
+    [(0, 0), (1, 1), (2, 2)],
+    [(0, 2), (1, 1), (2, 0)],
+];
+
 //////////////////////////////////////////////////////////////////////////////
 // Primary Structure
 //////////////////////////////////////////////////////////////////////////////
 
-/// Tic-tac-toe board.
-#[derive(Debug)]
-pub(crate) struct Board {
-    pub(crate) values: [[Option<Player>; 3]; 3],
+/// Tic-tac-toe board, holding player marks unless another cell type is
+/// given.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Board<T = Option<Player>> {
+    pub(crate) values: [[T; 3]; 3],
 }
 
-impl Board {
+impl<T: Copy> Board<Option<T>> {
     //////////////////////////////////
     // Instantiation
     //////////////////////////////////
@@ -35,81 +55,35 @@ impl Board {
     // Evaluation
     //////////////////////////////////
 
-    /// Check for winners on a given board, either `Some(Player)` or `None`.
-    pub(crate) fn winner(&self) -> Option<Player> {
-        let mut result = None;
-
-        let only_has = |set: &HashSet<Option<Player>>, player| {
-            set.len() == 1 && set.contains(&Some(player))
-        };
-
-        // Vertical checks
-        for col in 0..3 {
-            let column: HashSet<Option<Player>> =
-                { HashSet::from_iter(self.values[col].iter().cloned()) };
-
-            if only_has(&column, Player::X) {
-                result = Some(Player::X);
-                break;
-            } else if only_has(&column, Player::O) {
-                result = Some(Player::O);
-                break;
-            }
-        }
-
-        // Horizontal checks
-        if result.is_none() {
-            'outer: for row in 0..3 {
-                let mut row_vals: HashSet<Option<Player>> = HashSet::new();
-
-                for col in 0..3 {
-                    row_vals.insert(self.values[col][row]);
-                }
-
-                if only_has(&row_vals, Player::X) {
-                    result = Some(Player::X);
-                    break 'outer;
-                } else if only_has(&row_vals, Player::O) {
-                    result = Some(Player::O);
-                    break 'outer;
-                }
-            }
-        }
-
-        // Diagonal checks
-        if result.is_none() {
-            let mut s1: HashSet<Option<Player>> = HashSet::new();
-            let mut s2: HashSet<Option<Player>> = HashSet::new();
-
-            for n in 0..3 {
-                s1.insert(self.values[n][n]);
-                s2.insert(self.values[n][2 - n]);
-            }
-
-            if only_has(&s1, Player::X) || only_has(&s2, Player::X) {
-                result = Some(Player::X);
-            } else if only_has(&s1, Player::O) || only_has(&s2, Player::O) {
-                result = Some(Player::O);
-            }
-        }
-
-        result
+    /// Find the first line whose three values satisfy `predicate`.
+    pub(crate) fn find_line<F>(&self, predicate: F) -> Option<[Position; 3]>
+    where
+        F: Fn([Option<T>; 3]) -> bool,
+    {
+        LINES.iter().cloned().find(|line| {
+            predicate([
+                self.values[line[0].0][line[0].1],
+                self.values[line[1].0][line[1].1],
+                self.values[line[2].0][line[2].1],
+            ])
+        })
     }
 
     /// Check for a full board.
     pub(crate) fn is_full(&self) -> bool {
-        let mut result = true;
+        self.values.iter().flatten().all(|value| value.is_some())
+    }
+}
 
-        for col in 0..3 {
-            for row in 0..3 {
-                if self.values[col][row].is_none() {
-                    result = false;
-                    break;
-                }
-            }
-        }
+impl Board {
+    /// Check for winners on a given board, either `Some(Player)` or `None`.
+    pub(crate) fn winner(&self) -> Option<Player> {
+        let line = self.find_line(|values| {
+            values[0].is_some() && values.iter().all(|&v| v == values[0])
+        })?;
+        let (col, row) = line[0];
 
-        result
+        self.values[col][row]
     }
 }
 
@@ -121,13 +95,15 @@ impl Board {
 mod tests {
     use super::*;
 
-    fn from_array(values: [[Option<Player>; 3]; 3]) -> Self {
-        Self { values: values }
+    impl Board {
+        fn from_array(values: [[Option<Player>; 3]; 3]) -> Self {
+            Self { values }
+        }
     }
 
     #[test]
     fn test_new_board() {
-        let new_board = Board::new();
+        let new_board: Board = Board::new();
 
         for col in 0..3 {
             for row in 0..3 {
@@ -0,0 +1,171 @@
+//! Chess-style game clocks with an increment or delay per move.
+
+use super::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// How the per-move bonus is applied.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Bonus {
+    /// The bonus is added to the mover's clock after each move.
+    Increment,
+    /// The clock only starts running once the bonus has elapsed each move.
+    Delay,
+}
+
+/// Base time per player plus a bonus per move, in milliseconds.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct TimeControl {
+    pub(crate) base: u64,
+    pub(crate) bonus: u64,
+    pub(crate) kind: Bonus,
+}
+
+impl TimeControl {
+    /// Time control of `minutes` per player plus `seconds` per move.
+    pub(crate) fn new(minutes: u64, seconds: u64, kind: Bonus) -> Self {
+        Self {
+            base: minutes * 60_000,
+            bonus: seconds * 1_000,
+            kind,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Clocks of a two-player game, or no clocks for untimed play.
+#[derive(Clone, Debug)]
+pub(crate) struct Clock {
+    pub(crate) control: Option<TimeControl>,
+    /// Time left per player, indexed by turn order.
+    pub(crate) remaining: [u64; 2],
+    /// Time spent on the current move so far.
+    pub(crate) spent: u64,
+}
+
+impl Clock {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Setup full clocks under the given time control.
+    pub(crate) fn new(control: Option<TimeControl>) -> Self {
+        let base = control.map_or(0, |control| control.base);
+
+        Self {
+            control,
+            remaining: [base; 2],
+            spent: 0,
+        }
+    }
+
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Time left for the given player.
+    pub(crate) fn remaining(&self, player: Player) -> u64 {
+        self.remaining[player.index()]
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Run the clock of the player to move for `elapsed` milliseconds,
+    /// returning whether their flag fell.
+    pub(crate) fn tick(&mut self, player: Player, elapsed: u64) -> bool {
+        let control = match self.control {
+            Some(control) => control,
+            None => return false,
+        };
+
+        // Under delay, only time beyond the bonus is charged
+        let charged = match control.kind {
+            Bonus::Increment => elapsed,
+            Bonus::Delay => {
+                let free = control.bonus.saturating_sub(self.spent);
+                elapsed.saturating_sub(free)
+            }
+        };
+        let remaining = &mut self.remaining[player.index()];
+
+        self.spent += elapsed;
+        *remaining = remaining.saturating_sub(charged);
+
+        *remaining == 0
+    }
+
+    /// Finish the given player's move, adding any increment to their clock.
+    pub(crate) fn press(&mut self, player: Player) {
+        if let Some(control) = self.control {
+            if control.kind == Bonus::Increment {
+                self.remaining[player.index()] += control.bonus;
+            }
+        }
+
+        self.spent = 0;
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_untimed_never_flags() {
+        let mut clock = Clock::new(None);
+
+        assert!(!clock.tick(Player::X, 1_000_000));
+        assert_eq!(clock.remaining(Player::X), 0);
+    }
+
+    #[test]
+    fn test_increment() {
+        let mut clock =
+            Clock::new(Some(TimeControl::new(1, 2, Bonus::Increment)));
+
+        assert!(!clock.tick(Player::X, 5_000));
+        clock.press(Player::X);
+
+        assert_eq!(clock.remaining(Player::X), 57_000);
+        assert_eq!(clock.remaining(Player::O), 60_000);
+    }
+
+    #[test]
+    fn test_delay() {
+        let mut clock = Clock::new(Some(TimeControl::new(1, 3, Bonus::Delay)));
+
+        // Two ticks within the delay cost nothing, the third costs 1s
+        assert!(!clock.tick(Player::O, 2_000));
+        assert!(!clock.tick(Player::O, 1_000));
+        assert_eq!(clock.remaining(Player::O), 60_000);
+        assert!(!clock.tick(Player::O, 1_000));
+        assert_eq!(clock.remaining(Player::O), 59_000);
+
+        // The delay starts afresh, and is never banked
+        clock.press(Player::O);
+        assert!(!clock.tick(Player::X, 4_000));
+        assert_eq!(clock.remaining(Player::X), 59_000);
+        assert_eq!(clock.remaining(Player::O), 59_000);
+    }
+
+    #[test]
+    fn test_flag_falls() {
+        let mut clock =
+            Clock::new(Some(TimeControl::new(1, 0, Bonus::Increment)));
+
+        assert!(!clock.tick(Player::X, 59_900));
+        assert!(clock.tick(Player::X, 200));
+        assert_eq!(clock.remaining(Player::X), 0);
+    }
+}
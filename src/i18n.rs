@@ -0,0 +1,607 @@
+//! Message catalogs for every piece of text shown in the interface.
+//!
+//! Messages are looked up by key, and may hold numbered placeholders such as
+//! `{0}`, which translations are free to reorder. Messages depending on a
+//! count come in `.one` and `.other` forms, picked by each language's plural
+//! rule.
+
+use super::*;
+
+use std::fmt::Display;
+
+//////////////////////////////////////////////////////////////////////////////
+// Catalogs
+//////////////////////////////////////////////////////////////////////////////
+
+const ENGLISH: &[(&str, &str)] = &[
+    // Modes
+    ("mode.classic", "CLASSIC"),
+    ("mode.ultimate", "ULTIMATE"),
+    ("mode.qubic", "QUBIC"),
+    ("mode.gomoku", "GOMOKU"),
+    ("mode.connect_four", "CONNECT 4"),
+    ("mode.notakto", "NOTAKTO"),
+    ("mode.quantum", "QUANTUM"),
+    ("mode.order_chaos", "ORDER/CHAOS"),
+    ("mode.numerical", "NUMERICAL"),
+    ("mode.multiplayer", "MULTIPLAYER"),
+    // Buttons
+    ("button.reset", "RESET"),
+    ("button.settings", "SETTINGS"),
+    ("button.stats", "STATS"),
+    ("button.defaults", "DEFAULTS"),
+    ("button.add_profile", "ADD PROFILE"),
+    ("button.guest", "GUEST"),
+    ("button.export", "EXPORT"),
+    // Status and record
+    ("status.active", "Active player: {0}"),
+    ("status.wins", "{0} Wins!"),
+    ("status.draw", "It's a draw!"),
+    ("record.victories", "{0} victories: {1}"),
+    ("record.draws", "Draws: {0}"),
+    // Rule options
+    ("option.flat", "FLAT"),
+    ("option.torus", "TORUS"),
+    ("option.hide_edges", "HIDE EDGES"),
+    ("option.show_edges", "SHOW EDGES"),
+    ("option.hide_numbers", "HIDE NUMBERS"),
+    ("option.show_numbers", "SHOW NUMBERS"),
+    ("option.boards.one", "{0} BOARD"),
+    ("option.boards.other", "{0} BOARDS"),
+    ("option.normal", "NORMAL"),
+    ("option.misere", "MISÈRE"),
+    ("option.standard", "STANDARD"),
+    ("option.wild", "WILD"),
+    ("option.unlimited", "UNLIMITED"),
+    ("option.vanishing", "VANISHING"),
+    ("option.placement", "PLACEMENT"),
+    ("option.morris", "MORRIS"),
+    ("option.morris_diagonal", "MORRIS+DIAG"),
+    ("option.single_games", "SINGLE GAMES"),
+    ("option.best_of", "BEST OF {0}"),
+    ("option.first_to", "FIRST TO {0}"),
+    ("option.untimed", "UNTIMED"),
+    ("option.delay", "{0} DELAY {1}"),
+    ("option.place", "PLACE {0}"),
+    ("option.players", "{0} PLAYERS"),
+    ("option.freestyle", "FREESTYLE"),
+    ("option.renju", "RENJU"),
+    ("option.free", "FREE"),
+    ("option.swap", "SWAP"),
+    ("option.swap2", "SWAP2"),
+    ("option.black", "BLACK"),
+    ("option.white", "WHITE"),
+    ("option.place_two", "PLACE TWO"),
+    // Notices
+    ("notice.collapse", "{0} chooses where {1} collapses"),
+    ("notice.entangle", "Pick a second cell to entangle"),
+    ("notice.score", "Score: {0} {1}, {2} {3}"),
+    ("notice.killed", "{0} killed the last board"),
+    ("notice.timeout", "{0} ran out of time"),
+    ("notice.misere_line", "{0} completed a line"),
+    ("notice.move_limit", "Move limit of {0} reached"),
+    ("notice.repetition", "Threefold repetition"),
+    ("notice.move_piece", "{0}: move a piece"),
+    ("notice.move_count", "Move {0} of {1}"),
+    ("notice.forbidden", "Forbidden move: {0}"),
+    ("notice.place_colour", "{0}: place {1}"),
+    ("notice.choose_colour", "{0}: choose a colour"),
+    ("notice.colours", "{0} plays {1}, {2} plays {3}"),
+    // Names
+    ("colour.black", "black"),
+    ("colour.white", "white"),
+    ("colour.orange", "orange"),
+    ("colour.violet", "violet"),
+    ("foul.double_three", "double-three"),
+    ("foul.double_four", "double-four"),
+    ("foul.overline", "overline"),
+    ("role.order", "Order"),
+    ("role.chaos", "Chaos"),
+    // Matches
+    ("series.best_of", "Best of {0}"),
+    ("series.first_to", "First to {0}"),
+    ("series.won", "{0} wins the match {1}-{2}"),
+    ("series.score", "{0}: {1} {2} - {3} {4}"),
+    // Statistics
+    ("stats.player", "Player"),
+    ("stats.rating", "Rating"),
+    ("stats.progress", "Progress"),
+    ("stats.games", "Games"),
+    ("stats.wins", "W"),
+    ("stats.losses", "L"),
+    ("stats.draws", "D"),
+    ("stats.first", "First"),
+    ("stats.second", "Second"),
+    ("stats.streak", "Streak"),
+    ("streak.win.one", "{0} win"),
+    ("streak.win.other", "{0} wins"),
+    ("streak.loss.one", "{0} loss"),
+    ("streak.loss.other", "{0} losses"),
+    ("streak.draw.one", "{0} draw"),
+    ("streak.draw.other", "{0} draws"),
+    // Strategies
+    ("strategy.random", "CPU: RANDOM"),
+    ("strategy.minimax", "CPU: MINIMAX"),
+    // Themes
+    ("theme.light", "LIGHT"),
+    ("theme.dark", "DARK"),
+    ("theme.contrast", "HIGH CONTRAST"),
+    ("theme.colourblind", "COLOURBLIND"),
+    // Accessibility
+    ("aria.board", "Game board"),
+    ("aria.cell", "{0}, row {1}, column {2}"),
+    ("aria.board_cell", "{0}, board {1}, row {2}, column {3}"),
+    ("aria.layer_cell", "{0}, layer {1}, row {2}, column {3}"),
+    ("aria.empty", "empty"),
+    ("aria.played", "{0} played"),
+    ("aria.sub_board", "Board {0}"),
+    ("aria.layer", "Layer {0}"),
+    (
+        "notice.keys",
+        "Arrow keys move, Enter or Space plays, R resets",
+    ),
+];
+
+const SPANISH: &[(&str, &str)] = &[
+    // Modes
+    ("mode.classic", "CLÁSICO"),
+    ("mode.ultimate", "SUPREMO"),
+    ("mode.qubic", "QUBIC"),
+    ("mode.gomoku", "GOMOKU"),
+    ("mode.connect_four", "CONECTA 4"),
+    ("mode.notakto", "NOTAKTO"),
+    ("mode.quantum", "CUÁNTICO"),
+    ("mode.order_chaos", "ORDEN/CAOS"),
+    ("mode.numerical", "NUMÉRICO"),
+    ("mode.multiplayer", "MULTIJUGADOR"),
+    // Buttons
+    ("button.reset", "REINICIAR"),
+    ("button.settings", "AJUSTES"),
+    ("button.stats", "ESTADÍSTICAS"),
+    ("button.defaults", "PREDETERMINADOS"),
+    ("button.add_profile", "AÑADIR PERFIL"),
+    ("button.guest", "INVITADO"),
+    ("button.export", "EXPORTAR"),
+    // Status and record
+    ("status.active", "Jugador activo: {0}"),
+    ("status.wins", "¡{0} gana!"),
+    ("status.draw", "¡Empate!"),
+    ("record.victories", "Victorias de {0}: {1}"),
+    ("record.draws", "Empates: {0}"),
+    // Rule options
+    ("option.flat", "PLANO"),
+    ("option.torus", "TORO"),
+    ("option.hide_edges", "OCULTAR BORDES"),
+    ("option.show_edges", "MOSTRAR BORDES"),
+    ("option.hide_numbers", "OCULTAR NÚMEROS"),
+    ("option.show_numbers", "MOSTRAR NÚMEROS"),
+    ("option.boards.one", "{0} TABLERO"),
+    ("option.boards.other", "{0} TABLEROS"),
+    ("option.normal", "NORMAL"),
+    ("option.misere", "MISÈRE"),
+    ("option.standard", "ESTÁNDAR"),
+    ("option.wild", "COMODÍN"),
+    ("option.unlimited", "ILIMITADO"),
+    ("option.vanishing", "EVANESCENTE"),
+    ("option.placement", "COLOCACIÓN"),
+    ("option.morris", "MOLINO"),
+    ("option.morris_diagonal", "MOLINO+DIAG"),
+    ("option.single_games", "PARTIDAS SUELTAS"),
+    ("option.best_of", "AL MEJOR DE {0}"),
+    ("option.first_to", "PRIMERO A {0}"),
+    ("option.untimed", "SIN RELOJ"),
+    ("option.delay", "{0} RETARDO {1}"),
+    ("option.place", "COLOCAR {0}"),
+    ("option.players", "{0} JUGADORES"),
+    ("option.freestyle", "ESTILO LIBRE"),
+    ("option.renju", "RENJU"),
+    ("option.free", "LIBRE"),
+    ("option.swap", "SWAP"),
+    ("option.swap2", "SWAP2"),
+    ("option.black", "NEGRO"),
+    ("option.white", "BLANCO"),
+    ("option.place_two", "COLOCAR DOS"),
+    // Notices
+    ("notice.collapse", "{0} elige dónde colapsa {1}"),
+    (
+        "notice.entangle",
+        "Elige una segunda casilla para entrelazar",
+    ),
+    ("notice.score", "Puntuación: {0} {1}, {2} {3}"),
+    ("notice.killed", "{0} mató el último tablero"),
+    ("notice.timeout", "A {0} se le acabó el tiempo"),
+    ("notice.misere_line", "{0} completó una línea"),
+    ("notice.move_limit", "Límite de {0} movimientos alcanzado"),
+    ("notice.repetition", "Triple repetición"),
+    ("notice.move_piece", "{0}: mueve una pieza"),
+    ("notice.move_count", "Movimiento {0} de {1}"),
+    ("notice.forbidden", "Movimiento prohibido: {0}"),
+    ("notice.place_colour", "{0}: coloca {1}"),
+    ("notice.choose_colour", "{0}: elige un color"),
+    ("notice.colours", "{0} juega con {1}, {2} juega con {3}"),
+    // Names
+    ("colour.black", "negro"),
+    ("colour.white", "blanco"),
+    ("colour.orange", "naranja"),
+    ("colour.violet", "violeta"),
+    ("foul.double_three", "doble tres"),
+    ("foul.double_four", "doble cuatro"),
+    ("foul.overline", "línea larga"),
+    ("role.order", "Orden"),
+    ("role.chaos", "Caos"),
+    // Matches
+    ("series.best_of", "Al mejor de {0}"),
+    ("series.first_to", "Primero a {0}"),
+    ("series.won", "{0} gana el encuentro {1}-{2}"),
+    ("series.score", "{0}: {1} {2} - {3} {4}"),
+    // Statistics
+    ("stats.player", "Jugador"),
+    ("stats.rating", "Puntuación"),
+    ("stats.progress", "Progreso"),
+    ("stats.games", "Partidas"),
+    ("stats.wins", "G"),
+    ("stats.losses", "P"),
+    ("stats.draws", "E"),
+    ("stats.first", "Primero"),
+    ("stats.second", "Segundo"),
+    ("stats.streak", "Racha"),
+    ("streak.win.one", "{0} victoria"),
+    ("streak.win.other", "{0} victorias"),
+    ("streak.loss.one", "{0} derrota"),
+    ("streak.loss.other", "{0} derrotas"),
+    ("streak.draw.one", "{0} empate"),
+    ("streak.draw.other", "{0} empates"),
+    // Strategies
+    ("strategy.random", "CPU: ALEATORIA"),
+    ("strategy.minimax", "CPU: MINIMAX"),
+    // Themes
+    ("theme.light", "CLARO"),
+    ("theme.dark", "OSCURO"),
+    ("theme.contrast", "ALTO CONTRASTE"),
+    ("theme.colourblind", "DALTÓNICOS"),
+    // Accessibility
+    ("aria.board", "Tablero de juego"),
+    ("aria.cell", "{0}, fila {1}, columna {2}"),
+    ("aria.board_cell", "{0}, tablero {1}, fila {2}, columna {3}"),
+    ("aria.layer_cell", "{0}, capa {1}, fila {2}, columna {3}"),
+    ("aria.empty", "vacía"),
+    ("aria.played", "{0} jugó"),
+    ("aria.sub_board", "Tablero {0}"),
+    ("aria.layer", "Capa {0}"),
+    (
+        "notice.keys",
+        "Flechas para moverse, Intro o Espacio para jugar, R para reiniciar",
+    ),
+];
+
+const FRENCH: &[(&str, &str)] = &[
+    // Modes
+    ("mode.classic", "CLASSIQUE"),
+    ("mode.ultimate", "ULTIME"),
+    ("mode.qubic", "QUBIC"),
+    ("mode.gomoku", "GOMOKU"),
+    ("mode.connect_four", "PUISSANCE 4"),
+    ("mode.notakto", "NOTAKTO"),
+    ("mode.quantum", "QUANTIQUE"),
+    ("mode.order_chaos", "ORDRE/CHAOS"),
+    ("mode.numerical", "NUMÉRIQUE"),
+    ("mode.multiplayer", "MULTIJOUEUR"),
+    // Buttons
+    ("button.reset", "RECOMMENCER"),
+    ("button.settings", "RÉGLAGES"),
+    ("button.stats", "STATISTIQUES"),
+    ("button.defaults", "PAR DÉFAUT"),
+    ("button.add_profile", "AJOUTER UN PROFIL"),
+    ("button.guest", "INVITÉ"),
+    ("button.export", "EXPORTER"),
+    // Status and record
+    ("status.active", "Joueur actif : {0}"),
+    ("status.wins", "{0} gagne !"),
+    ("status.draw", "Match nul !"),
+    ("record.victories", "Victoires de {0} : {1}"),
+    ("record.draws", "Nuls : {0}"),
+    // Rule options
+    ("option.flat", "PLAT"),
+    ("option.torus", "TORE"),
+    ("option.hide_edges", "MASQUER LES BORDS"),
+    ("option.show_edges", "AFFICHER LES BORDS"),
+    ("option.hide_numbers", "MASQUER LES NUMÉROS"),
+    ("option.show_numbers", "AFFICHER LES NUMÉROS"),
+    ("option.boards.one", "{0} PLATEAU"),
+    ("option.boards.other", "{0} PLATEAUX"),
+    ("option.normal", "NORMAL"),
+    ("option.misere", "MISÈRE"),
+    ("option.standard", "STANDARD"),
+    ("option.wild", "JOKER"),
+    ("option.unlimited", "ILLIMITÉ"),
+    ("option.vanishing", "ÉVANESCENT"),
+    ("option.placement", "POSE"),
+    ("option.morris", "MOULIN"),
+    ("option.morris_diagonal", "MOULIN+DIAG"),
+    ("option.single_games", "PARTIES SIMPLES"),
+    ("option.best_of", "MEILLEUR DE {0}"),
+    ("option.first_to", "PREMIER À {0}"),
+    ("option.untimed", "SANS PENDULE"),
+    ("option.delay", "{0} DÉLAI {1}"),
+    ("option.place", "POSER {0}"),
+    ("option.players", "{0} JOUEURS"),
+    ("option.freestyle", "STYLE LIBRE"),
+    ("option.renju", "RENJU"),
+    ("option.free", "LIBRE"),
+    ("option.swap", "SWAP"),
+    ("option.swap2", "SWAP2"),
+    ("option.black", "NOIR"),
+    ("option.white", "BLANC"),
+    ("option.place_two", "POSER DEUX"),
+    // Notices
+    ("notice.collapse", "{0} choisit où {1} s'effondre"),
+    (
+        "notice.entangle",
+        "Choisissez une deuxième case à intriquer",
+    ),
+    ("notice.score", "Score : {0} {1}, {2} {3}"),
+    ("notice.killed", "{0} a tué le dernier plateau"),
+    ("notice.timeout", "{0} n'a plus de temps"),
+    ("notice.misere_line", "{0} a complété une ligne"),
+    ("notice.move_limit", "Limite de {0} coups atteinte"),
+    ("notice.repetition", "Triple répétition"),
+    ("notice.move_piece", "{0} : déplacez une pièce"),
+    ("notice.move_count", "Coup {0} sur {1}"),
+    ("notice.forbidden", "Coup interdit : {0}"),
+    ("notice.place_colour", "{0} : posez {1}"),
+    ("notice.choose_colour", "{0} : choisissez une couleur"),
+    ("notice.colours", "{0} joue {1}, {2} joue {3}"),
+    // Names
+    ("colour.black", "noir"),
+    ("colour.white", "blanc"),
+    ("colour.orange", "orange"),
+    ("colour.violet", "violet"),
+    ("foul.double_three", "double trois"),
+    ("foul.double_four", "double quatre"),
+    ("foul.overline", "ligne trop longue"),
+    ("role.order", "Ordre"),
+    ("role.chaos", "Chaos"),
+    // Matches
+    ("series.best_of", "Meilleur de {0}"),
+    ("series.first_to", "Premier à {0}"),
+    ("series.won", "{0} remporte le match {1}-{2}"),
+    ("series.score", "{0} : {1} {2} - {3} {4}"),
+    // Statistics
+    ("stats.player", "Joueur"),
+    ("stats.rating", "Classement"),
+    ("stats.progress", "Progression"),
+    ("stats.games", "Parties"),
+    ("stats.wins", "V"),
+    ("stats.losses", "D"),
+    ("stats.draws", "N"),
+    ("stats.first", "Premier"),
+    ("stats.second", "Second"),
+    ("stats.streak", "Série"),
+    ("streak.win.one", "{0} victoire"),
+    ("streak.win.other", "{0} victoires"),
+    ("streak.loss.one", "{0} défaite"),
+    ("streak.loss.other", "{0} défaites"),
+    ("streak.draw.one", "{0} nul"),
+    ("streak.draw.other", "{0} nuls"),
+    // Strategies
+    ("strategy.random", "CPU : ALÉATOIRE"),
+    ("strategy.minimax", "CPU : MINIMAX"),
+    // Themes
+    ("theme.light", "CLAIR"),
+    ("theme.dark", "SOMBRE"),
+    ("theme.contrast", "CONTRASTE ÉLEVÉ"),
+    ("theme.colourblind", "DALTONIENS"),
+    // Accessibility
+    ("aria.board", "Plateau de jeu"),
+    ("aria.cell", "{0}, ligne {1}, colonne {2}"),
+    ("aria.board_cell", "{0}, plateau {1}, ligne {2}, colonne {3}"),
+    ("aria.layer_cell", "{0}, couche {1}, ligne {2}, colonne {3}"),
+    ("aria.empty", "vide"),
+    ("aria.played", "{0} a joué"),
+    ("aria.sub_board", "Plateau {0}"),
+    ("aria.layer", "Couche {0}"),
+    (
+        "notice.keys",
+        "Flèches pour se déplacer, Entrée ou Espace pour jouer, \
+         R pour recommencer",
+    ),
+];
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Languages the interface is translated into.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize,
+)]
+pub(crate) enum Language {
+    #[default]
+    English,
+    Spanish,
+    French,
+}
+
+impl Language {
+    /// Every supported language, in selector order.
+    pub(crate) const ALL: [Language; 3] =
+        [Language::English, Language::Spanish, Language::French];
+
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Name of the language, in that language.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::French => "Français",
+        }
+    }
+
+    /// Messages of the language, by key.
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => ENGLISH,
+            Language::Spanish => SPANISH,
+            Language::French => FRENCH,
+        }
+    }
+
+    /// Whether `count` takes the singular form.
+    fn is_singular(self, count: u32) -> bool {
+        match self {
+            Language::English | Language::Spanish => count == 1,
+            Language::French => count <= 1,
+        }
+    }
+
+    //////////////////////////////////
+    // Translation
+    //////////////////////////////////
+
+    /// Message for the given key, falling back to English, then to the key
+    /// itself.
+    pub(crate) fn text(self, key: &str) -> &str {
+        let find = |catalog: &'static [(&'static str, &'static str)]| {
+            catalog
+                .iter()
+                .find(|&&(entry, _)| entry == key)
+                .map(|&(_, message)| message)
+        };
+
+        find(self.catalog())
+            .or_else(|| find(ENGLISH))
+            .unwrap_or(key)
+    }
+
+    /// Message for the given key, with its placeholders filled in.
+    pub(crate) fn format(self, key: &str, args: &[&dyn Display]) -> String {
+        fill(self.text(key), args)
+    }
+
+    /// Message for `count` of something, as the `.one` or `.other` form of
+    /// the given key, with `{0}` standing for the count.
+    pub(crate) fn plural(self, key: &str, count: u32) -> String {
+        let form = if self.is_singular(count) {
+            "one"
+        } else {
+            "other"
+        };
+
+        self.format(&format!("{}.{}", key, form), &[&count])
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Formatting
+//////////////////////////////////////////////////////////////////////////////
+
+/// Replace each `{n}` in the template with the n-th argument.
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut text = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        text.push_str(&rest[..open]);
+        rest = &rest[open..];
+
+        let close = match rest.find('}') {
+            Some(close) => close,
+            None => break,
+        };
+
+        match rest[1..close]
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| args.get(n))
+        {
+            Some(arg) => text.push_str(&arg.to_string()),
+            None => text.push_str(&rest[..=close]),
+        }
+
+        rest = &rest[close + 1..];
+    }
+
+    text.push_str(rest);
+
+    text
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbered placeholders used by a message, in increasing order.
+    fn placeholders(message: &str) -> Vec<usize> {
+        let mut found: Vec<usize> = message
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split('}').next()?.parse().ok())
+            .collect();
+
+        found.sort();
+        found.dedup();
+        found
+    }
+
+    #[test]
+    fn test_every_key_in_every_catalog() {
+        for &language in Language::ALL.iter() {
+            let catalog = language.catalog();
+
+            assert_eq!(catalog.len(), ENGLISH.len(), "{:?}", language);
+
+            for &(key, message) in ENGLISH.iter() {
+                let translation = catalog
+                    .iter()
+                    .find(|&&(entry, _)| entry == key)
+                    .map(|&(_, translation)| translation);
+
+                match translation {
+                    Some(translation) => assert_eq!(
+                        placeholders(translation),
+                        placeholders(message),
+                        "{:?} {}",
+                        language,
+                        key
+                    ),
+                    None => panic!("{:?} is missing {}", language, key),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let english = Language::English;
+
+        assert_eq!(
+            english.format("status.active", &[&"Chi"]),
+            "Active player: Chi"
+        );
+        assert_eq!(
+            Language::Spanish.format("series.won", &[&"Omi", &2, &1]),
+            "Omi gana el encuentro 2-1"
+        );
+        assert_eq!(fill("{1} {0} {x} {", &[&'a', &'b']), "b a {x} {");
+    }
+
+    #[test]
+    fn test_plurals() {
+        assert_eq!(Language::English.plural("option.boards", 1), "1 BOARD");
+        assert_eq!(Language::English.plural("option.boards", 0), "0 BOARDS");
+        assert_eq!(Language::French.plural("streak.win", 0), "0 victoire");
+        assert_eq!(Language::Spanish.plural("streak.loss", 3), "3 derrotas");
+    }
+
+    #[test]
+    fn test_missing_key_falls_back() {
+        assert_eq!(Language::French.text("no.such.key"), "no.such.key");
+    }
+}
@@ -1,46 +1,138 @@
-#![recursion_limit = "256"]
+#![recursion_limit = "512"]
 
 //! Tic-tac-toe written in Rust using the Yew framework.
 
 mod board;
+mod clock;
+mod i18n;
+mod mnk;
+mod notakto;
+mod numerical;
+mod order_chaos;
+mod profiles;
+mod quantum;
+mod qubic;
+mod rating;
+mod renju;
+mod series;
+mod settings;
+mod share;
+mod strategy;
+mod ultimate;
 
-use board::Position;
+use board::{Cells, Position};
 
-use std::collections::HashSet;
-use std::iter::FromIterator;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
+use stdweb::web::event::IEvent;
+use stdweb::web::Date;
 use yew::prelude::*;
+use yew::format::{Json, Text};
+use yew::services::interval::IntervalTask;
+use yew::services::storage::{Area, StorageService};
+use yew::services::IntervalService;
 use yew::{html, Component, ComponentLink, Html, Renderable, ShouldRender};
 
 use board::Board;
+use clock::{Bonus, Clock, TimeControl};
+use i18n::Language;
+use mnk::{Choice, Grid, MnkState, Opening, Phase, Rules};
+use notakto::NotaktoState;
+use numerical::NumericalState;
+use order_chaos::{OrderChaosState, Role};
+use profiles::{Entity, Profiles};
+use quantum::QuantumState;
+use qubic::{CubePosition, QubicState};
+use renju::Foul;
+use series::{Series, SeriesFormat};
+use settings::{Settings, Theme};
+use share::{Move, Shared};
+use strategy::Strategy;
+use ultimate::UltimateState;
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Marks each player may keep on the board under vanishing rules.
+const VANISHING_MARKS: usize = 3;
+
+/// Moves after which a vanishing game, which can never fill up, is drawn.
+const VANISHING_MOVE_LIMIT: u32 = 50;
+
+/// Pieces each player places before moving them under morris rules.
+const MORRIS_PIECES: usize = 3;
+
+/// Occurrences of the same position that draw a morris game.
+const MORRIS_REPETITIONS: u32 = 3;
+
+/// Milliseconds between clock ticks.
+const CLOCK_TICK_MS: u64 = 100;
+
+/// File the profiles are exported to, as read by the rankings example.
+const EXPORT_FILE: &str = "tic-tac-yew-profiles.json";
+
+/// Latest games shown in a profile's rating chart.
+const RATING_CHART_GAMES: usize = 20;
 
 //////////////////////////////////////////////////////////////////////////////
 // Auxiliary Structures
 //////////////////////////////////////////////////////////////////////////////
 
-/// Two tic-tac-toe player labels.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+/// Tic-tac-toe player labels, in turn order. Two-player games only use X
+/// and O.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 enum Player {
     X,
     O,
+    Delta,
+    Psi,
 }
 
 impl Player {
-    /// Return the other player label.
-    fn other(&self) -> Self {
+    /// Every player label, in turn order.
+    const ALL: [Player; 4] =
+        [Player::X, Player::O, Player::Delta, Player::Psi];
+
+    /// Position of the label in turn order.
+    fn index(self) -> usize {
         match self {
-            Player::X => Player::O,
-            Player::O => Player::X,
+            Player::X => 0,
+            Player::O => 1,
+            Player::Delta => 2,
+            Player::Psi => 3,
         }
     }
+
+    /// Return the other player label of a two-player game.
+    fn other(&self) -> Self {
+        self.next(2)
+    }
+
+    /// Return the label moving after this one when `count` players take
+    /// turns in order.
+    fn next(&self, count: usize) -> Self {
+        Player::ALL[(self.index() + 1) % count]
+    }
 }
 
 /// Result of an attempted move.
 #[derive(Debug, Eq, PartialEq)]
 enum MoveOutcome {
     Win(Player),
+    /// The given player completed a line under misère rules and loses.
+    Lose(Player),
     Draw,
     Switch,
+    /// The move was made, and the same player moves again.
+    Continue,
+    /// The given player's flag fell and they lose on time.
+    Timeout(Player),
+    /// The move breaks a rule restricting the active player.
+    Forbidden(Foul),
     NoChange,
 }
 
@@ -49,30 +141,67 @@ enum MoveOutcome {
 //////////////////////////////////////////////////////////////////////////////
 
 /// Win/Loss record over the course of multiple games.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Record {
-    xwins: u32,
-    owins: u32,
+    /// Wins per player, indexed by turn order.
+    wins: [u32; 4],
     draws: u32,
 }
 
 impl Record {
     fn new() -> Self {
         Self {
-            xwins: 0,
-            owins: 0,
+            wins: [0; 4],
             draws: 0,
         }
     }
+
+    /// Number of games won by the given player.
+    fn wins(&self, player: Player) -> u32 {
+        self.wins[player.index()]
+    }
+
+    /// Count a win for the given player.
+    fn add_win(&mut self, player: Player) {
+        self.wins[player.index()] += 1;
+    }
 }
 
 /// Tic-tac-toe game state.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct GameState {
     board: Board,
     turn: Player,
+    /// Player who moved first in the current game.
+    first: Player,
     ongoing: bool,
     winner: Option<Player>,
+    /// Completing three in a row loses instead of winning.
+    misere: bool,
+    /// Either player may place either mark; the mover owns any line made.
+    wild: bool,
+    /// Mark the active player will place under wild rules.
+    mark: Player,
+    /// Placing a fourth mark removes that player's oldest one.
+    vanishing: bool,
+    /// Cells each player has placed in, oldest first.
+    placed: HashMap<Player, VecDeque<Position>>,
+    /// After placing three pieces each, players move them instead.
+    morris: bool,
+    /// Morris pieces may also move diagonally.
+    diagonal: bool,
+    /// Occurrences of each (board, player to move) position.
+    repetitions: HashMap<(Cells, Player), u32>,
+    moves: u32,
+    /// Number of the move that put each standing mark in its cell, counting
+    /// from one.
+    numbers: [[Option<u32>; 3]; 3],
+    /// Every move of the current game, oldest first.
+    history: Vec<Move>,
+    /// Per-player clocks, running only for the player in `turn`.
+    clock: Clock,
+    /// Match being played, if any, which decides who moves first.
+    series: Option<Series>,
     log: Record,
 }
 
@@ -86,8 +215,22 @@ impl GameState {
         Self {
             board: Board::new(),
             turn: Player::X,
+            first: Player::X,
             ongoing: true,
             winner: None,
+            misere: false,
+            wild: false,
+            mark: Player::X,
+            vanishing: false,
+            placed: HashMap::new(),
+            morris: false,
+            diagonal: false,
+            repetitions: HashMap::new(),
+            moves: 0,
+            numbers: [[None; 3]; 3],
+            history: Vec::new(),
+            clock: Clock::new(None),
+            series: None,
             log: Record::new(),
         }
     }
@@ -98,51 +241,307 @@ impl GameState {
 
     /// Attempt to make a move on the current board.
     fn play(&mut self, (col, row): Position) -> MoveOutcome {
-        if !self.ongoing {
+        if !self.ongoing || self.must_shift() {
             MoveOutcome::NoChange
-        } else if self.board.values[col][row].is_none()
-            && col < 3
+        } else if col < 3
             && row < 3
+            && self.board.values[col][row].is_none()
         {
-            self.board.values[col][row] = Some(self.turn);
+            if self.vanishing {
+                let placed = self.placed.entry(self.turn).or_default();
 
-            match self.board.winner() {
-                Some(player) => {
-                    self.ongoing = false;
-                    self.winner = Some(player);
+                placed.push_back((col, row));
 
-                    if player == Player::X {
-                        self.log.xwins += 1;
-                    } else {
-                        self.log.owins += 1;
+                if placed.len() > VANISHING_MARKS {
+                    if let Some((old_col, old_row)) = placed.pop_front() {
+                        self.board.values[old_col][old_row] = None;
+                        self.numbers[old_col][old_row] = None;
                     }
-
-                    MoveOutcome::Win(player)
                 }
-                None => {
-                    if self.board.is_full() {
-                        self.ongoing = false;
-                        self.log.draws += 1;
+            }
 
-                        MoveOutcome::Draw
-                    } else {
-                        self.turn = self.turn.other();
+            self.board.values[col][row] = Some(self.placed_mark());
+            self.numbers[col][row] = Some(self.moves + 1);
+            self.history.push(Move::Place((col, row), self.placed_mark()));
 
-                        MoveOutcome::Switch
-                    }
-                }
-            }
+            self.settle()
         } else {
             MoveOutcome::NoChange
         }
     }
 
-    /// Reset everthing besides the game log.
+    /// Attempt to move one of the active player's marks to an adjacent empty
+    /// cell, once all of their pieces are placed under morris rules.
+    fn shift(&mut self, from: Position, to: Position) -> MoveOutcome {
+        let (from_col, from_row) = from;
+        let (to_col, to_row) = to;
+
+        if !self.ongoing
+            || !self.must_shift()
+            || from_col >= 3
+            || from_row >= 3
+            || to_col >= 3
+            || to_row >= 3
+            || self.board.values[from_col][from_row] != Some(self.turn)
+            || self.board.values[to_col][to_row].is_some()
+            || !self.is_adjacent(from, to)
+        {
+            return MoveOutcome::NoChange;
+        }
+
+        self.board.values[from_col][from_row] = None;
+        self.board.values[to_col][to_row] = Some(self.turn);
+        self.numbers[from_col][from_row] = None;
+        self.numbers[to_col][to_row] = Some(self.moves + 1);
+        self.history.push(Move::Shift(from, to));
+
+        self.settle()
+    }
+
+    /// Make a move as recorded in the history, picking its mark first under
+    /// wild rules.
+    fn make(&mut self, played: Move) -> MoveOutcome {
+        match played {
+            Move::Place(pos, mark) => {
+                if self.wild {
+                    self.select_mark(mark);
+                }
+
+                self.play(pos)
+            }
+            Move::Shift(from, to) => self.shift(from, to),
+        }
+    }
+
+    /// Run the active player's clock for `elapsed` milliseconds, ending the
+    /// game if their flag falls. Clocks stand still once the game is over.
+    fn tick(&mut self, elapsed: u64) -> MoveOutcome {
+        if !self.ongoing || !self.clock.tick(self.turn, elapsed) {
+            return MoveOutcome::NoChange;
+        }
+
+        self.flag_fall()
+    }
+
+    /// End the game as a loss on time for the player to move.
+    fn flag_fall(&mut self) -> MoveOutcome {
+        let loser = self.turn;
+
+        self.ongoing = false;
+        self.winner = Some(loser.other());
+        self.log.add_win(loser.other());
+        self.score_series(Some(loser.other()));
+
+        MoveOutcome::Timeout(loser)
+    }
+
+    /// Reset everthing besides the game log and match score, starting a new
+    /// match once the current one is decided.
     fn reset(&mut self) {
+        if let Some(series) = &mut self.series {
+            if series.winner.is_some() {
+                *series = Series::new(series.format);
+            }
+        }
+
         self.board = Board::new();
-        self.turn = Player::X;
+        self.turn = self.series.as_ref().map_or(Player::X, Series::first);
+        self.first = self.turn;
         self.ongoing = true;
         self.winner = None;
+        self.mark = Player::X;
+        self.placed.clear();
+        self.moves = 0;
+        self.numbers = [[None; 3]; 3];
+        self.history.clear();
+        self.repetitions.clear();
+        self.clock = Clock::new(self.clock.control);
+    }
+
+    /// Evaluate the board after a mark has been placed or moved, ending the
+    /// game or passing the turn.
+    fn settle(&mut self) -> MoveOutcome {
+        self.moves += 1;
+        self.clock.press(self.turn);
+
+        match self.board.winner() {
+            Some(mark) => {
+                // Under wild rules the line belongs to whoever made it
+                let player = if self.wild { self.turn } else { mark };
+                let winner = if self.misere {
+                    player.other()
+                } else {
+                    player
+                };
+
+                self.ongoing = false;
+                self.winner = Some(winner);
+
+                self.log.add_win(winner);
+                self.score_series(Some(winner));
+
+                if self.misere {
+                    MoveOutcome::Lose(player)
+                } else {
+                    MoveOutcome::Win(player)
+                }
+            }
+            None => {
+                let drawn = if self.vanishing {
+                    self.moves >= VANISHING_MOVE_LIMIT
+                } else if self.morris {
+                    self.count_repetition() >= MORRIS_REPETITIONS
+                } else {
+                    self.board.is_full()
+                };
+
+                if drawn {
+                    self.ongoing = false;
+                    self.log.draws += 1;
+                    self.score_series(None);
+
+                    MoveOutcome::Draw
+                } else {
+                    self.turn = self.turn.other();
+
+                    MoveOutcome::Switch
+                }
+            }
+        }
+    }
+
+    /// Count a finished game towards the match, if one is being played.
+    fn score_series(&mut self, winner: Option<Player>) {
+        if let Some(series) = &mut self.series {
+            series.record(winner);
+        }
+    }
+
+    /// Count how often the current position, with the opponent to move, has
+    /// occurred, including this occurrence.
+    fn count_repetition(&mut self) -> u32 {
+        let key = (self.board.values, self.turn.other());
+        let count = self.repetitions.entry(key).or_insert(0);
+
+        *count += 1;
+        *count
+    }
+
+    /// Check whether the active player has placed all their pieces and must
+    /// move one instead of placing more.
+    fn must_shift(&self) -> bool {
+        let placed = self
+            .board
+            .values
+            .iter()
+            .flat_map(|column| column.iter())
+            .filter(|&&value| value == Some(self.turn))
+            .count();
+
+        self.morris && placed >= MORRIS_PIECES
+    }
+
+    /// Check whether two cells are neighbours under the morris rules.
+    fn is_adjacent(&self, (c1, r1): Position, (c2, r2): Position) -> bool {
+        let dc = (c1 as isize - c2 as isize).abs();
+        let dr = (r1 as isize - r2 as isize).abs();
+
+        if self.diagonal {
+            dc.max(dr) == 1
+        } else {
+            dc + dr == 1
+        }
+    }
+
+    /// Mark the active player will lose with their next move under
+    /// vanishing rules.
+    fn next_to_vanish(&self) -> Option<Position> {
+        match self.placed.get(&self.turn) {
+            Some(placed) if self.vanishing && self.ongoing => {
+                if placed.len() == VANISHING_MARKS {
+                    placed.front().cloned()
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Player who lost on time, if the game ended that way.
+    fn flagged(&self) -> Option<Player> {
+        match self.winner {
+            // Every other win completes a line on the board
+            Some(winner) if self.board.winner().is_none() => {
+                Some(winner.other())
+            }
+            _ => None,
+        }
+    }
+
+    /// Cell the latest move placed or moved a mark into.
+    fn last_move(&self) -> Option<Position> {
+        POSITIONS
+            .iter()
+            .cloned()
+            .filter(|&(col, row)| self.numbers[col][row].is_some())
+            .max_by_key(|&(col, row)| self.numbers[col][row])
+    }
+
+    /// Mark the active player places with their next move.
+    fn placed_mark(&self) -> Player {
+        if self.wild {
+            self.mark
+        } else {
+            self.turn
+        }
+    }
+
+    /// Pick the mark to place next under wild rules.
+    fn select_mark(&mut self, mark: Player) {
+        self.mark = mark;
+    }
+
+    /// Switch misère rules on or off, starting a fresh game.
+    fn set_misere(&mut self, misere: bool) {
+        self.misere = misere;
+        self.reset();
+    }
+
+    /// Switch wild rules on or off, starting a fresh game.
+    fn set_wild(&mut self, wild: bool) {
+        self.wild = wild;
+        self.morris &= !wild;
+        self.reset();
+    }
+
+    /// Switch vanishing rules on or off, starting a fresh game.
+    fn set_vanishing(&mut self, vanishing: bool) {
+        self.vanishing = vanishing;
+        self.morris &= !vanishing;
+        self.reset();
+    }
+
+    /// Switch morris movement on or off, starting a fresh game. Moving
+    /// pieces replaces the wild and vanishing rules.
+    fn set_morris(&mut self, morris: bool, diagonal: bool) {
+        self.morris = morris;
+        self.diagonal = diagonal;
+        self.wild &= !morris;
+        self.vanishing &= !morris;
+        self.reset();
+    }
+
+    /// Start a match in the given format, or return to single games.
+    fn set_series(&mut self, format: Option<SeriesFormat>) {
+        self.series = format.map(Series::new);
+        self.reset();
+    }
+
+    /// Switch to a different time control, starting a fresh game.
+    fn set_time_control(&mut self, control: Option<TimeControl>) {
+        self.clock = Clock::new(control);
+        self.reset();
     }
 }
 
@@ -150,11 +549,101 @@ impl GameState {
 // Yew State Machine
 //////////////////////////////////////////////////////////////////////////////
 
-type Model = GameState;
+/// Selectable game variants.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+enum Mode {
+    Classic,
+    Ultimate,
+    Qubic,
+    Gomoku,
+    ConnectFour,
+    Notakto,
+    Quantum,
+    OrderChaos,
+    Numerical,
+    Multiplayer,
+}
+
+/// Application state, holding one game per variant.
+struct Model {
+    mode: Mode,
+    classic: GameState,
+    ultimate: UltimateState,
+    qubic: QubicState,
+    gomoku: MnkState,
+    connect_four: MnkState,
+    multiplayer: MnkState,
+    notakto: NotaktoState,
+    quantum: QuantumState,
+    order_chaos: OrderChaosState,
+    numerical: NumericalState,
+    /// Morris piece picked up and waiting to be moved.
+    selected: Option<Position>,
+    /// Cell under the keyboard cursor, in the coordinates of `board_size`.
+    cursor: Position,
+    /// Show ghost copies of the opposite edges around toroidal grids.
+    ghosts: bool,
+    /// Player names, colours and glyphs, saved to local storage.
+    settings: Settings,
+    show_settings: bool,
+    /// Player profiles and their game history, saved to local storage.
+    profiles: Profiles,
+    show_stats: bool,
+    /// Name typed for a new profile.
+    draft: String,
+    storage: StorageService,
+    /// URL fragment last written for the classic game, if any.
+    fragment: Option<String>,
+    /// Time of the latest clock tick, in milliseconds since the epoch.
+    last_tick: f64,
+    /// Spoken description of the latest move, e.g. "Chi played, row 1,
+    /// column 2".
+    announcement: String,
+    /// Interval driving the game clocks, cancelled when dropped.
+    _ticker: IntervalTask,
+}
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum Msg {
     Click(Position),
+    UltimateClick(Position, Position),
+    QubicClick(CubePosition),
+    GridClick(Position),
+    SetRules(Rules),
+    SetGhosts(bool),
+    Choose(Choice),
+    SetMisere(bool),
+    SetWild(bool),
+    SetVanishing(bool),
+    SetMorris(bool, bool),
+    Select(Position),
+    Shift(Position, Position),
+    SelectMark(Player),
+    SetTimeControl(Option<TimeControl>),
+    SetSeries(Option<SeriesFormat>),
+    Tick,
+    NotaktoClick(usize, Position),
+    SetBoardCount(usize),
+    QuantumClick(Position),
+    Collapse(Position),
+    NumberClick(Position),
+    SelectNumber(u8),
+    SetMode(Mode),
+    Key(String),
+    MoveCursor(isize, isize),
+    ToggleSettings,
+    SetName(Player, String),
+    SetColour(Player, String),
+    SetGlyph(Player, String),
+    SetLanguage(Language),
+    SetTheme(Theme),
+    SetMoveNumbers(bool),
+    ResetSettings,
+    ToggleStats,
+    SetDraft(String),
+    AddProfile,
+    SeatProfile(Player, Option<Entity>),
+    LoadLink,
     Reset,
 }
 
@@ -162,104 +651,2288 @@ impl Component for Model {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        Self::new()
+    fn create(_: Self::Properties, mut link: ComponentLink<Self>) -> Self {
+        let ticker = IntervalService::new().spawn(
+            Duration::from_millis(CLOCK_TICK_MS),
+            link.send_back(|_| Msg::Tick),
+        );
+        let storage = StorageService::new(Area::Local);
+        let Json(restored) = storage.restore(settings::STORAGE_KEY);
+        let settings: Settings = restored
+            .ok()
+            .filter(Settings::is_complete)
+            .unwrap_or_default();
+        let Json(restored) = storage.restore(profiles::STORAGE_KEY);
+        let mut profiles: Profiles = restored
+            .ok()
+            .filter(Profiles::is_complete)
+            .unwrap_or_default();
+
+        profiles.restore_ratings();
+
+        // Opening a shared link picks up the game where it was left
+        share::on_fragment_change(link.send_back(|_| Msg::LoadLink));
+        let shared = share::fragment().and_then(|hash| share::decode(&hash));
+
+        let mut model = Self {
+            mode: Mode::Classic,
+            classic: GameState::new(),
+            ultimate: UltimateState::new(),
+            qubic: QubicState::new(),
+            gomoku: MnkState::new(Rules::gomoku()),
+            connect_four: MnkState::new(Rules::connect_four()),
+            multiplayer: MnkState::new(Rules::multiplayer()),
+            notakto: NotaktoState::new(3),
+            quantum: QuantumState::new(),
+            order_chaos: OrderChaosState::new(),
+            numerical: NumericalState::new(),
+            selected: None,
+            cursor: (0, 0),
+            ghosts: true,
+            settings,
+            show_settings: false,
+            profiles,
+            show_stats: false,
+            draft: String::new(),
+            storage,
+            fragment: None,
+            last_tick: Date::now(),
+            announcement: String::new(),
+            _ticker: ticker,
+        };
+
+        if let Some(shared) = shared {
+            model.open_link(shared);
+        }
+
+        model
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
-        match msg {
+        let mode = self.mode;
+        let (was_ongoing, mover, ..) = self.status();
+        let loading = matches!(msg, Msg::LoadLink);
+        // Cell played by the message and what came of it, for announcing
+        let mut played = None;
+
+        let render = match msg {
+            // Strategies make their own moves
+            Msg::Click(_) | Msg::Select(_) | Msg::Shift(..)
+                if self.profiles.strategy(self.classic.turn).is_some() =>
+            {
+                false
+            }
             Msg::Click(pos) => {
-                self.play(pos);
+                played = Some((pos, self.classic.play(pos)));
+                self.selected = None;
+                true
+            }
+            Msg::Select(pos) => {
+                self.selected = Some(pos);
+                true
+            }
+            Msg::Shift(from, to) => {
+                played = Some((to, self.classic.shift(from, to)));
+                self.selected = None;
+                true
+            }
+            Msg::SetMorris(morris, diagonal) => {
+                self.classic.set_morris(morris, diagonal);
+                self.selected = None;
+                true
+            }
+            Msg::UltimateClick(outer, inner) => {
+                let pos = (outer.0 * 3 + inner.0, outer.1 * 3 + inner.1);
+                played = Some((pos, self.ultimate.play(outer, inner)));
+                true
+            }
+            Msg::QubicClick(pos) => {
+                let (col, row, layer) = pos;
+                let cell = (layer * qubic::SIZE + col, row);
+                played = Some((cell, self.qubic.play(pos)));
+                true
+            }
+            Msg::GridClick(pos) => {
+                let outcome = if self.mode == Mode::OrderChaos {
+                    Some(self.order_chaos.play(pos))
+                } else {
+                    self.grid_game_mut().map(|game| game.play(pos))
+                };
+                played = outcome.map(|outcome| (pos, outcome));
+                true
+            }
+            Msg::SetRules(rules) => {
+                if let Some(game) = self.grid_game_mut() {
+                    game.configure(rules);
+                }
+                true
+            }
+            Msg::SetGhosts(ghosts) => {
+                self.ghosts = ghosts;
+                true
+            }
+            Msg::Choose(choice) => {
+                self.gomoku.choose(choice);
+                true
+            }
+            Msg::SetMisere(misere) => {
+                self.classic.set_misere(misere);
+                true
+            }
+            Msg::SetWild(wild) => {
+                self.classic.set_wild(wild);
+                true
+            }
+            Msg::SetVanishing(vanishing) => {
+                self.classic.set_vanishing(vanishing);
+                true
+            }
+            Msg::SetTimeControl(control) => {
+                self.classic.set_time_control(control);
+                true
+            }
+            Msg::SetSeries(format) => {
+                self.classic.set_series(format);
+                true
+            }
+            Msg::Tick => {
+                // Browsers throttle intervals in background tabs, so charge
+                // the time that really passed rather than the interval
+                let now = Date::now();
+                let elapsed = (now - self.last_tick).max(0.0) as u64;
+
+                self.last_tick = now;
+
+                let moved = self.play_strategy(now as u64);
+
+                // The clocks keep running while another mode is on screen
+                let running = self.classic.ongoing
+                    && self.classic.clock.control.is_some();
+                let outcome = if running {
+                    self.classic.tick(elapsed)
+                } else {
+                    MoveOutcome::NoChange
+                };
+
+                // A flag falling off screen still counts towards the profiles
+                if let MoveOutcome::Timeout(_) = outcome {
+                    let game = &self.classic;
+
+                    if self.mode != Mode::Classic
+                        && self.profiles.record(
+                            Mode::Classic,
+                            2,
+                            game.first,
+                            game.winner,
+                        )
+                    {
+                        self.save_profiles();
+                    }
+                }
+
+                moved || running
+            }
+            Msg::SelectMark(mark) => {
+                if self.mode == Mode::OrderChaos {
+                    self.order_chaos.select_symbol(mark);
+                } else {
+                    self.classic.select_mark(mark);
+                }
+                true
+            }
+            Msg::NotaktoClick(index, pos) => {
+                let (col, row) = pos;
+                let cell = (index * 3 + col, row);
+                played = Some((cell, self.notakto.play(index, pos)));
+                true
+            }
+            Msg::SetBoardCount(count) => {
+                self.notakto.set_count(count);
+                true
+            }
+            Msg::QuantumClick(pos) => {
+                played = Some((pos, self.quantum.play(pos)));
+                true
+            }
+            Msg::Collapse(pos) => {
+                played = Some((pos, self.quantum.collapse(pos)));
+                true
+            }
+            Msg::NumberClick(pos) => {
+                played = Some((pos, self.numerical.play(pos)));
+                true
+            }
+            Msg::SelectNumber(number) => {
+                self.numerical.select_number(number);
+                true
+            }
+            Msg::SetMode(mode) => {
+                self.mode = mode;
+                self.announcement.clear();
+                true
+            }
+            Msg::MoveCursor(dx, dy) => {
+                let (width, height) = self.board_size();
+                let (col, row) = self.cursor;
+                let step = |from: usize, by: isize, size: usize| {
+                    (from as isize + by).clamp(0, size as isize - 1) as usize
+                };
+
+                self.cursor = (step(col, dx, width), step(row, dy, height));
+                true
+            }
+            // Keys stand in for the message they are bound to
+            Msg::Key(key) => match self.key_msg(&key) {
+                Some(msg) => return self.update(msg),
+                None => false,
+            },
+            Msg::ToggleSettings => {
+                self.show_settings = !self.show_settings;
                 true
             }
+            Msg::SetName(player, name) => {
+                self.settings.set_name(player, &name);
+                self.save_settings();
+                true
+            }
+            Msg::SetColour(player, colour) => {
+                self.settings.set_colour(player, &colour);
+                self.save_settings();
+                true
+            }
+            Msg::SetGlyph(player, glyph) => {
+                self.settings.set_glyph(player, &glyph);
+                self.save_settings();
+                true
+            }
+            Msg::SetLanguage(language) => {
+                self.settings.language = language;
+                self.save_settings();
+                true
+            }
+            Msg::SetTheme(theme) => {
+                self.settings.theme = theme;
+                self.save_settings();
+                true
+            }
+            Msg::SetMoveNumbers(numbers) => {
+                self.settings.move_numbers = numbers;
+                self.save_settings();
+                true
+            }
+            Msg::ResetSettings => {
+                self.settings = Settings {
+                    language: self.settings.language,
+                    theme: self.settings.theme,
+                    move_numbers: self.settings.move_numbers,
+                    ..Settings::default()
+                };
+                self.save_settings();
+                true
+            }
+            Msg::ToggleStats => {
+                self.show_stats = !self.show_stats;
+                true
+            }
+            Msg::SetDraft(name) => {
+                self.draft = name;
+                false
+            }
+            Msg::AddProfile => {
+                if self.profiles.add(&self.draft) {
+                    self.draft.clear();
+                    self.save_profiles();
+                }
+                true
+            }
+            Msg::SeatProfile(seat, entity) => {
+                self.profiles.seat(seat, entity);
+                self.save_profiles();
+                true
+            }
+            Msg::LoadLink => {
+                let hash = share::fragment();
+
+                match hash.as_deref().and_then(share::decode) {
+                    Some(shared) if hash != self.fragment => {
+                        self.open_link(shared);
+                        true
+                    }
+                    _ => false,
+                }
+            }
             Msg::Reset => {
-                self.reset();
+                self.selected = None;
+                self.announcement.clear();
+
+                match self.mode {
+                    Mode::Classic => self.classic.reset(),
+                    Mode::Ultimate => self.ultimate.reset(),
+                    Mode::Qubic => self.qubic.reset(),
+                    Mode::Gomoku => self.gomoku.reset(),
+                    Mode::ConnectFour => self.connect_four.reset(),
+                    Mode::Notakto => self.notakto.reset(),
+                    Mode::Quantum => self.quantum.reset(),
+                    Mode::OrderChaos => self.order_chaos.reset(),
+                    Mode::Numerical => self.numerical.reset(),
+                    Mode::Multiplayer => self.multiplayer.reset(),
+                }
                 true
             }
+        };
+
+        match played {
+            Some((_, MoveOutcome::NoChange))
+            | Some((_, MoveOutcome::Forbidden(_)))
+            | None => {}
+            Some((pos, _)) => self.announce(mover, pos),
+        }
+
+        // Games only count towards the profiles once they end in play
+        if self.mode == mode && was_ongoing && !loading && !self.status().0 {
+            self.record_game();
+        }
+
+        // Keep the address bar pointing at the game in play
+        let fragment = match self.mode {
+            Mode::Classic => Some(share::encode(&self.classic)),
+            Mode::Gomoku => share::encode_mnk(self.mode, &self.gomoku),
+            Mode::ConnectFour => {
+                share::encode_mnk(self.mode, &self.connect_four)
+            }
+            Mode::Multiplayer => {
+                share::encode_mnk(self.mode, &self.multiplayer)
+            }
+            _ => None,
+        };
+
+        if fragment != self.fragment {
+            share::set_fragment(fragment.as_deref());
+            self.fragment = fragment;
         }
+
+        // Keep the cursor on the board when it changes size
+        let (width, height) = self.board_size();
+        let (col, row) = self.cursor;
+        self.cursor = (col.min(width - 1), row.min(height - 1));
+
+        render
     }
 }
 
-impl Renderable<Model> for Model {
-    fn view(&self) -> Html<Self> {
-        // CSS labels
-        let xteam_label = "chi";
-        let oteam_label = "omi";
-        let neutr_label = "neutral";
+//////////////////////////////////
+// Rendering Helpers
+//////////////////////////////////
 
-        // Game status rendering
-        let (game_status, indicator) = {
-            if self.ongoing {
-                if self.turn == Player::X {
-                    ("Active player: Chi", xteam_label)
-                } else {
-                    ("Active player: Omi", oteam_label)
-                }
-            } else {
-                match self.winner {
-                    Some(Player::X) => ("Chi Wins!", xteam_label),
-                    Some(Player::O) => ("Omi Wins!", oteam_label),
-                    None => ("It's a draw!", neutr_label),
-                }
+/// All cell positions of a 3x3 grid, in reading order.
+const POSITIONS: [Position; 9] = [
+    (0, 0), (1, 0), (2, 0),
+    (0, 1), (1, 1), (2, 1),
+    (0, 2), (1, 2), (2, 2),
+];
+
+/// Render a 3x3 board row by row, so that assistive technology can follow
+/// its layout.
+fn view_rows<F>(view_tile: F) -> Vec<Html<Model>>
+where
+    F: Fn(&Position) -> Html<Model>,
+{
+    POSITIONS
+        .chunks(3)
+        .map(|row| {
+            html! {
+                <div class="board-row" role="row">
+                    { for row.iter().map(&view_tile) }
+                </div>
+            }
+        })
+        .collect()
+}
+
+/// Element id of the board cell at the given keyboard cursor position.
+fn cell_id((col, row): Position) -> String {
+    format!("cell-{}-{}", col, row)
+}
+
+/// `data:` URL downloading the given JSON, percent-encoded.
+fn json_data_url(json: &str) -> String {
+    let mut url = String::from("data:application/json,");
+
+    for byte in json.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            url.push(byte as char);
+        } else {
+            url.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    url
+}
+
+/// CSS labels for the column and row of a 3x3 grid cell.
+fn grid_labels((col, row): Position) -> (&'static str, &'static str) {
+    let col_label = match col {
+        0 => "col-0",
+        1 => "col-1",
+        _ => "col-2",
+    };
+    let row_label = match row {
+        0 => "row-0",
+        1 => "row-1",
+        _ => "row-2",
+    };
+
+    (col_label, row_label)
+}
+
+/// CSS label for the indicator and won boards of a given seat.
+fn team_label(seat: Player) -> &'static str {
+    match seat {
+        Player::X => "chi",
+        Player::O => "omi",
+        Player::Delta => "delta",
+        Player::Psi => "psi",
+    }
+}
+
+/// Catalog key of a stone colour's name in k-in-a-row games.
+fn colour_key(colour: Player) -> &'static str {
+    match colour {
+        Player::X => "colour.black",
+        Player::O => "colour.white",
+        Player::Delta => "colour.orange",
+        Player::Psi => "colour.violet",
+    }
+}
+
+/// Render an option button sending `msg` when clicked, marked when it is the
+/// `selected` one.
+fn view_option(label: &str, selected: bool, msg: Msg) -> Html<Model> {
+    let selected = if selected { "selected" } else { "" };
+
+    html! {
+        <button
+            type="button"
+            class=("option-button", selected)
+            onclick=move |_| msg.clone()
+        >
+            { label }
+        </button>
+    }
+}
+
+/// Render the latest ratings of a profile as a bar chart, scaled between
+/// their lowest and highest values.
+fn view_rating_chart(history: &[i32]) -> Html<Model> {
+    let recent = &history[history.len().saturating_sub(RATING_CHART_GAMES)..];
+    let low = recent.iter().min().cloned().unwrap_or(rating::INITIAL);
+    let high = recent.iter().max().cloned().unwrap_or(rating::INITIAL);
+
+    let view_bar = |rating: &i32| {
+        let height = 4 + 16 * (rating - low) / (high - low).max(1);
+        let style = format!("height: {}px;", height);
+
+        html! {
+            <span class="rating-bar" style=&style title=rating></span>
+        }
+    };
+
+    html! {
+        <span>{ for recent.iter().map(view_bar) }</span>
+    }
+}
+
+/// Render a button switching to the given rules, marked when they are the
+/// `current` ones.
+fn view_rule_option(
+    current: Rules,
+    &(rules, label): &(Rules, &'static str),
+) -> Html<Model> {
+    view_option(label, rules == current, Msg::SetRules(rules))
+}
+
+/// Label of a quantum mark drawn with the given glyph, e.g. "X₃".
+fn mark_name(glyph: &str, mark: quantum::Mark) -> String {
+    let subscript = mark
+        .number
+        .to_string()
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .filter_map(|digit| std::char::from_u32(0x2080 + digit))
+        .collect::<String>();
+
+    format!("{}{}", glyph, subscript)
+}
+
+/// Score in half-points, written out in points.
+fn score_name(halves: u32) -> String {
+    match (halves / 2, halves % 2) {
+        (0, 1) => "½".to_string(),
+        (points, 1) => format!("{}½", points),
+        (points, _) => points.to_string(),
+    }
+}
+
+/// Clock reading as minutes, seconds and tenths, e.g. "2:05.3".
+fn clock_time(millis: u64) -> String {
+    let tenths = millis / 100;
+
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+/// CSS label for a tile holding the given value.
+fn tile_label(value: Option<Player>) -> &'static str {
+    match value {
+        Some(Player::X) => "x-tile",
+        Some(Player::O) => "o-tile",
+        Some(Player::Delta) => "delta-tile",
+        Some(Player::Psi) => "psi-tile",
+        None => "empty-tile",
+    }
+}
+
+impl Model {
+    /// Display name of a seat in the current mode.
+    fn side_name(&self, seat: Player) -> &str {
+        match (self.mode, seat) {
+            (Mode::OrderChaos, Player::X) => self.text(Role::Order.key()),
+            (Mode::OrderChaos, Player::O) => self.text(Role::Chaos.key()),
+            _ => self.settings.name(seat),
+        }
+    }
+
+    /// Display name of a profile or strategy.
+    fn entity_name<'a>(&self, entity: &'a Entity) -> &'a str {
+        match entity {
+            Entity::Profile(name) => name,
+            Entity::Strategy(strategy) => self.text(strategy.key()),
+        }
+    }
+
+    /// Message for the given catalog key, in the chosen language.
+    fn text<'a>(&self, key: &'a str) -> &'a str {
+        self.settings.language.text(key)
+    }
+
+    /// Message for the given catalog key, with its placeholders filled in.
+    fn format(&self, key: &str, args: &[&dyn Display]) -> String {
+        self.settings.language.format(key, args)
+    }
+
+    /// Message for `count` of something, in the chosen language.
+    fn plural(&self, key: &str, count: u32) -> String {
+        self.settings.language.plural(key, count)
+    }
+
+    /// Name of a match format, e.g. "Best of 3".
+    fn series_name(&self, format: SeriesFormat) -> String {
+        match format {
+            SeriesFormat::BestOf(games) => {
+                self.format("series.best_of", &[&games])
+            }
+            SeriesFormat::FirstTo(wins) => {
+                self.format("series.first_to", &[&wins])
+            }
+        }
+    }
+
+    /// Label of a button placing the given mark, e.g. "PLACE X".
+    fn place_label(&self, mark: Player) -> String {
+        self.format("option.place", &[&self.settings.side(mark).glyph])
+    }
+
+    /// Quantum mark label, drawn with its player's chosen glyph.
+    fn mark_name(&self, mark: quantum::Mark) -> String {
+        mark_name(&self.settings.side(mark.player).glyph, mark)
+    }
+
+    /// Whether the active game is ongoing, whose turn it is, who won it and
+    /// its game log.
+    fn status(&self) -> (bool, Player, Option<Player>, &Record) {
+        match self.mode {
+            Mode::Classic => {
+                let game = &self.classic;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+            Mode::Ultimate => {
+                let game = &self.ultimate;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+            Mode::Qubic => {
+                let game = &self.qubic;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+            Mode::Gomoku => {
+                let game = &self.gomoku;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+            Mode::ConnectFour => {
+                let game = &self.connect_four;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+            Mode::Notakto => {
+                let game = &self.notakto;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+            Mode::Quantum => {
+                let game = &self.quantum;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+            Mode::OrderChaos => {
+                let game = &self.order_chaos;
+                let winner = game.winner.map(Role::seat);
+                (game.ongoing, game.turn.seat(), winner, &game.log)
+            }
+            Mode::Numerical => {
+                let game = &self.numerical;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+            Mode::Multiplayer => {
+                let game = &self.multiplayer;
+                (game.ongoing, game.turn, game.winner, &game.log)
+            }
+        }
+    }
+
+    /// Number of players taking turns in the current mode.
+    fn player_count(&self) -> usize {
+        match self.mode {
+            Mode::Multiplayer => self.multiplayer.rules.players,
+            _ => 2,
+        }
+    }
+
+    /// Width and height of the active board as navigated by keyboard, with
+    /// sub-boards and layers laid side by side as on screen.
+    fn board_size(&self) -> (usize, usize) {
+        let grid = match self.mode {
+            Mode::Classic | Mode::Quantum | Mode::Numerical => return (3, 3),
+            Mode::Ultimate => return (9, 9),
+            Mode::Qubic => return (qubic::SIZE * qubic::SIZE, qubic::SIZE),
+            Mode::Notakto => return (3 * self.notakto.boards.len(), 3),
+            Mode::Gomoku => &self.gomoku.grid,
+            Mode::ConnectFour => &self.connect_four.grid,
+            Mode::OrderChaos => &self.order_chaos.grid,
+            Mode::Multiplayer => &self.multiplayer.grid,
+        };
+
+        (grid.width, grid.height)
+    }
+
+    /// Message bound to a key pressed on the board, if any.
+    fn key_msg(&self, key: &str) -> Option<Msg> {
+        match key {
+            "ArrowLeft" => Some(Msg::MoveCursor(-1, 0)),
+            "ArrowRight" => Some(Msg::MoveCursor(1, 0)),
+            "ArrowUp" => Some(Msg::MoveCursor(0, -1)),
+            "ArrowDown" => Some(Msg::MoveCursor(0, 1)),
+            "Enter" | " " => self.cell_msg(self.cursor),
+            "r" | "R" => Some(Msg::Reset),
+            _ => None,
+        }
+    }
+
+    /// Message sent by clicking the cell at the given cursor position.
+    fn cell_msg(&self, (x, y): Position) -> Option<Msg> {
+        let (width, height) = self.board_size();
+
+        if x >= width || y >= height {
+            return None;
+        }
+
+        let msg = match self.mode {
+            Mode::Classic => self.classic_msg((x, y)),
+            Mode::Ultimate => {
+                Msg::UltimateClick((x / 3, y / 3), (x % 3, y % 3))
+            }
+            Mode::Qubic => {
+                Msg::QubicClick((x % qubic::SIZE, y, x / qubic::SIZE))
+            }
+            Mode::Notakto => Msg::NotaktoClick(x / 3, (x % 3, y)),
+            Mode::Quantum => self.quantum_msg((x, y)),
+            Mode::Numerical => Msg::NumberClick((x, y)),
+            Mode::Gomoku
+            | Mode::ConnectFour
+            | Mode::OrderChaos
+            | Mode::Multiplayer => Msg::GridClick((x, y)),
+        };
+
+        Some(msg)
+    }
+
+    /// Message sent by clicking a classic cell.
+    fn classic_msg(&self, pos: Position) -> Msg {
+        let game = &self.classic;
+        let shifting = game.ongoing && game.must_shift();
+        let (col, row) = pos;
+        let own = game.board.values[col][row] == Some(game.turn);
+
+        // Morris pieces are picked up, then dropped on a neighbour
+        match self.selected {
+            _ if shifting && own => Msg::Select(pos),
+            Some(from) if shifting => Msg::Shift(from, pos),
+            _ => Msg::Click(pos),
+        }
+    }
+
+    /// Message sent by clicking a quantum cell, collapsing a cycle if one
+    /// awaits it.
+    fn quantum_msg(&self, pos: Position) -> Msg {
+        if self.quantum.cycle.is_some() {
+            Msg::Collapse(pos)
+        } else {
+            Msg::QuantumClick(pos)
+        }
+    }
+
+    /// Spoken contents of a cell holding the given mark.
+    fn mark_text(&self, value: Option<Player>) -> String {
+        match value {
+            None => self.text("aria.empty").to_string(),
+            // Symbols belong to neither side in Order and Chaos
+            Some(mark) if self.mode == Mode::OrderChaos => {
+                self.settings.side(mark).glyph.clone()
+            }
+            Some(player) => self.side_name(player).to_string(),
+        }
+    }
+
+    /// Spoken description of a cell, locating it on its board.
+    fn cell_label(&self, content: &str, (x, y): Position) -> String {
+        match self.mode {
+            Mode::Ultimate => {
+                let board = y / 3 * 3 + x / 3 + 1;
+                self.format(
+                    "aria.board_cell",
+                    &[&content, &board, &(y % 3 + 1), &(x % 3 + 1)],
+                )
+            }
+            Mode::Notakto => self.format(
+                "aria.board_cell",
+                &[&content, &(x / 3 + 1), &(y + 1), &(x % 3 + 1)],
+            ),
+            Mode::Qubic => self.format(
+                "aria.layer_cell",
+                &[
+                    &content,
+                    &(x / qubic::SIZE + 1),
+                    &(y + 1),
+                    &(x % qubic::SIZE + 1),
+                ],
+            ),
+            _ => self.format("aria.cell", &[&content, &(y + 1), &(x + 1)]),
+        }
+    }
+
+    /// CSS label marking the cell under the keyboard cursor.
+    fn cursor_label(&self, pos: Position) -> &'static str {
+        if pos == self.cursor {
+            "cursor-tile"
+        } else {
+            ""
+        }
+    }
+
+    /// Active m,n,k-game, if the current mode is one.
+    fn grid_game_mut(&mut self) -> Option<&mut MnkState> {
+        match self.mode {
+            Mode::Gomoku => Some(&mut self.gomoku),
+            Mode::ConnectFour => Some(&mut self.connect_four),
+            Mode::Multiplayer => Some(&mut self.multiplayer),
+            _ => None,
+        }
+    }
+
+    /// Save the player settings to local storage.
+    fn save_settings(&mut self) {
+        self.storage
+            .store(settings::STORAGE_KEY, Json(&self.settings));
+    }
+
+    /// Save the profiles and their history to local storage.
+    fn save_profiles(&mut self) {
+        self.storage
+            .store(profiles::STORAGE_KEY, Json(&self.profiles));
+    }
+
+    /// Switch to a game opened from a link. The lifetime log, and for the
+    /// classic board the match and time control, carry over to it.
+    fn open_link(&mut self, shared: Shared) {
+        match shared {
+            Shared::Classic(mut game) => {
+                let current = &mut self.classic;
+
+                game.log = std::mem::replace(&mut current.log, Record::new());
+                game.series = current.series.take();
+                game.clock = Clock::new(current.clock.control);
+                self.classic = game;
+                self.mode = Mode::Classic;
+            }
+            Shared::Mnk(mode, mut game) => {
+                self.mode = mode;
+
+                if let Some(current) = self.grid_game_mut() {
+                    let log = Record::new();
+
+                    game.log = std::mem::replace(&mut current.log, log);
+                    *current = game;
+                }
+            }
+        }
+
+        self.selected = None;
+        self.announcement.clear();
+    }
+
+    /// Let a strategy seated for the player to move make its classic move,
+    /// seeded by `seed`. Only moves made on screen are played, so that they
+    /// can be followed. Returns whether a move was made.
+    fn play_strategy(&mut self, seed: u64) -> bool {
+        let game = &self.classic;
+        let played = match self.profiles.strategy(game.turn) {
+            Some(strategy) if self.mode == Mode::Classic => {
+                strategy.choose(game, seed)
+            }
+            _ => None,
+        };
+
+        match played {
+            Some(played) => {
+                let mover = self.classic.turn;
+                let pos = match played {
+                    Move::Place(pos, _) | Move::Shift(_, pos) => pos,
+                };
+
+                self.classic.make(played);
+                self.selected = None;
+                self.announce(mover, pos);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Describe a move just made by the given player for screen readers.
+    fn announce(&mut self, mover: Player, pos: Position) {
+        let played = self.format("aria.played", &[&self.side_name(mover)]);
+
+        self.announcement = self.cell_label(&played, pos);
+    }
+
+    /// Add the game just finished to the seated profiles' history.
+    fn record_game(&mut self) {
+        let (_, _, winner, _) = self.status();
+        let first = match self.mode {
+            Mode::Classic => self.classic.first,
+            _ => Player::X,
+        };
+
+        if self.profiles.record(self.mode, self.player_count(), first, winner)
+        {
+            self.save_profiles();
+        }
+    }
+
+    /// Render the classic 3x3 board.
+    fn view_classic(&self) -> Html<Self> {
+        let game = &self.classic;
+        let next_to_vanish = game.next_to_vanish();
+        let last_move = game.last_move();
+
+        let view_tile = |pos: &Position| {
+            let pos = *pos;
+            let (col, row) = pos;
+            let (col_label, row_label) = grid_labels(pos);
+            let value = game.board.values[col][row];
+            let tile_label = tile_label(value);
+            let mark_label = if Some(pos) == self.selected {
+                "selected-tile"
+            } else if Some(pos) == next_to_vanish {
+                "vanishing"
+            } else {
+                ""
+            };
+            let last_label = if Some(pos) == last_move {
+                "last-move"
+            } else {
+                ""
+            };
+            let (number_label, number) = match game.numbers[col][row] {
+                Some(number) => ("numbered-tile", number.to_string()),
+                None => ("", String::new()),
+            };
+
+            let label = self.cell_label(&self.mark_text(value), pos);
+            let msg = self.classic_msg(pos);
+
+            html! {
+                <div
+                    class=(
+                        "tile",
+                        tile_label,
+                        mark_label,
+                        last_label,
+                        number_label,
+                        self.cursor_label(pos),
+                        col_label,
+                        row_label,
+                    )
+                    id=cell_id(pos)
+                    role="gridcell"
+                    aria-label=label
+                    data-number=number
+                    onclick=move |_| msg.clone()
+                >
+                </div>
+            }
+        };
+
+        html! {
+            <div class="game-board">
+                { for view_rows(view_tile).into_iter() }
+            </div>
+        }
+    }
+
+    /// Render the ultimate board as a 3x3 grid of sub-boards.
+    fn view_ultimate(&self) -> Html<Self> {
+        let game = &self.ultimate;
+
+        let view_board = |outer: &Position| {
+            let outer = *outer;
+            let (outer_col, outer_row) = outer;
+            let (col_label, row_label) = grid_labels(outer);
+            let board = &game.boards[outer_col][outer_row];
+
+            let board_label = match game.meta.values[outer_col][outer_row] {
+                Some(Player::X) => "chi",
+                Some(Player::O) => "omi",
+                Some(Player::Delta) => "delta",
+                Some(Player::Psi) => "psi",
+                None if game.is_playable(outer) => "active-board",
+                None => "inactive-board",
+            };
+
+            let view_tile = |inner: &Position| {
+                let inner = *inner;
+                let (col, row) = inner;
+                let (col_label, row_label) = grid_labels(inner);
+                let value = board.values[col][row];
+                let tile_label = tile_label(value);
+                let pos = (outer_col * 3 + col, outer_row * 3 + row);
+                let label = self.cell_label(&self.mark_text(value), pos);
+
+                html! {
+                    <div
+                        class=(
+                            "tile",
+                            tile_label,
+                            self.cursor_label(pos),
+                            col_label,
+                            row_label,
+                        )
+                        id=cell_id(pos)
+                        role="gridcell"
+                        aria-label=label
+                        onclick=|_| Msg::UltimateClick(outer, inner)
+                    >
+                    </div>
+                }
+            };
+
+            let number = outer_row * 3 + outer_col + 1;
+
+            // Each sub-board is a grid of its own, so its rows are real
+            html! {
+                <div
+                    class=("sub-board", board_label, col_label, row_label)
+                    role="gridcell"
+                >
+                    <div
+                        class="sub-grid"
+                        role="grid"
+                        aria-label=self.format("aria.sub_board", &[&number])
+                    >
+                        { for view_rows(view_tile).into_iter() }
+                    </div>
+                </div>
+            }
+        };
+
+        html! {
+            <div class=("game-board", "ultimate-board")>
+                { for view_rows(view_board).into_iter() }
+            </div>
+        }
+    }
+
+    /// Render the Qubic cube as four 4x4 layers side by side.
+    fn view_qubic(&self) -> Html<Self> {
+        let cube = &self.qubic.cube;
+
+        let view_layer = |layer: usize| {
+            let view_tile = |(col, row): Position| {
+                let pos = (col, row, layer);
+                let value = cube.get(pos);
+                let tile_label = tile_label(value);
+                let cell = (layer * qubic::SIZE + col, row);
+                let label = self.cell_label(&self.mark_text(value), cell);
+
+                html! {
+                    <div
+                        class=(
+                            "tile",
+                            "qubic-tile",
+                            tile_label,
+                            self.cursor_label(cell),
+                        )
+                        id=cell_id(cell)
+                        role="gridcell"
+                        aria-label=label
+                        onclick=|_| Msg::QubicClick(pos)
+                    >
+                    </div>
+                }
+            };
+            let view_row = |row: usize| {
+                html! {
+                    <div class="board-row" role="row">
+                        {
+                            for (0..qubic::SIZE)
+                                .map(|col| view_tile((col, row)))
+                        }
+                    </div>
+                }
+            };
+            let number = layer + 1;
+
+            html! {
+                <div class="qubic-layer" role="gridcell">
+                    <div
+                        class="sub-grid"
+                        role="grid"
+                        aria-label=self.format("aria.layer", &[&number])
+                    >
+                        { for (0..qubic::SIZE).map(view_row) }
+                    </div>
+                </div>
+            }
+        };
+
+        html! {
+            <div class=("game-board", "qubic-board")>
+                <div class="board-row" role="row">
+                    { for (0..qubic::SIZE).map(view_layer) }
+                </div>
+            </div>
+        }
+    }
+
+    /// Render an arbitrarily sized grid, row by row.
+    ///
+    /// Toroidal grids may be bordered by ghost copies of their opposite
+    /// edges, so that lines wrapping around are visible.
+    fn view_grid(&self, grid: &Grid) -> Html<Self> {
+        let border = if grid.wrap && self.ghosts { 1 } else { 0 };
+        let width = grid.width + 2 * border;
+        let height = grid.height + 2 * border;
+
+        let tile_style =
+            format!("width: {}%; height: 90%;", 90 / width.max(height));
+        let row_style = format!("height: {}%;", 100 / height);
+
+        let view_row = |row: usize| {
+            let view_tile = |col: usize| {
+                let pos = (
+                    (col + grid.width - border) % grid.width,
+                    (row + grid.height - border) % grid.height,
+                );
+                let value = grid.get(pos);
+                let tile_label = tile_label(value);
+                let ghost = col < border
+                    || row < border
+                    || col >= grid.width + border
+                    || row >= grid.height + border;
+
+                // Ghost copies are left out of the accessible grid
+                if ghost {
+                    return html! {
+                        <div
+                            class=(
+                                "tile",
+                                "grid-tile",
+                                tile_label,
+                                "ghost-tile",
+                            )
+                            style=&tile_style
+                            aria-hidden="true"
+                            onclick=|_| Msg::GridClick(pos)
+                        >
+                        </div>
+                    };
+                }
+
+                let label = self.cell_label(&self.mark_text(value), pos);
+
+                html! {
+                    <div
+                        class=(
+                            "tile",
+                            "grid-tile",
+                            tile_label,
+                            self.cursor_label(pos),
+                        )
+                        style=&tile_style
+                        id=cell_id(pos)
+                        role="gridcell"
+                        aria-label=label
+                        onclick=|_| Msg::GridClick(pos)
+                    >
+                    </div>
+                }
+            };
+
+            html! {
+                <div class="grid-row" style=&row_style role="row">
+                    { for (0..width).map(view_tile) }
+                </div>
+            }
+        };
+
+        html! {
+            <div class=("game-board", "grid-board")>
+                { for (0..height).map(view_row) }
+            </div>
+        }
+    }
+
+    /// Render the toroidal rule toggle for an m,n,k-game, plus the ghost
+    /// edge toggle while it is on.
+    fn view_wrap_options(&self, current: Rules) -> Html<Self> {
+        let view_rules = |option: &(Rules, &'static str)| {
+            view_rule_option(current, option)
+        };
+        let view_ghosts = |&(ghosts, label): &(bool, &'static str)| {
+            view_option(label, ghosts == self.ghosts, Msg::SetGhosts(ghosts))
+        };
+
+        let wrap_options = [
+            (Rules { wrap: false, ..current }, self.text("option.flat")),
+            (Rules { wrap: true, ..current }, self.text("option.torus")),
+        ];
+        let ghost_options = if current.wrap {
+            vec![
+                (false, self.text("option.hide_edges")),
+                (true, self.text("option.show_edges")),
+            ]
+        } else {
+            Vec::new()
+        };
+
+        html! {
+            <div class="option-group">
+                { for wrap_options.iter().map(view_rules) }
+                { for ghost_options.iter().map(view_ghosts) }
+            </div>
+        }
+    }
+
+    /// Render the Notakto boards side by side, greying out dead ones.
+    fn view_notakto(&self) -> Html<Self> {
+        let game = &self.notakto;
+
+        let view_board = |index: usize| {
+            let board = &game.boards[index];
+            let board_label = if game.dead[index] {
+                "dead-board"
+            } else {
+                "live-board"
+            };
+
+            let view_tile = |pos: &Position| {
+                let (col, row) = *pos;
+                let (col_label, row_label) = grid_labels(*pos);
+                let value = board.values[col][row];
+                let tile_label = tile_label(value);
+                let cell = (index * 3 + col, row);
+                let label = self.cell_label(&self.mark_text(value), cell);
+
+                html! {
+                    <div
+                        class=(
+                            "tile",
+                            tile_label,
+                            self.cursor_label(cell),
+                            col_label,
+                            row_label,
+                        )
+                        id=cell_id(cell)
+                        role="gridcell"
+                        aria-label=label
+                        onclick=|_| Msg::NotaktoClick(index, (col, row))
+                    >
+                    </div>
+                }
+            };
+
+            let number = index + 1;
+
+            html! {
+                <div class=("notakto-board", board_label) role="gridcell">
+                    <div
+                        class="sub-grid"
+                        role="grid"
+                        aria-label=self.format("aria.sub_board", &[&number])
+                    >
+                        { for view_rows(view_tile).into_iter() }
+                    </div>
+                </div>
+            }
+        };
+
+        html! {
+            <div class=("game-board", "notakto-boards")>
+                <div class="board-row" role="row">
+                    { for (0..game.boards.len()).map(view_board) }
+                </div>
+            </div>
+        }
+    }
+
+    /// Render the quantum board, listing the spooky marks in each cell.
+    fn view_quantum(&self) -> Html<Self> {
+        let game = &self.quantum;
+        let collapsible =
+            game.cycle.map(|mark| game.board.moves[mark.number - 1]);
+
+        let view_tile = |pos: &Position| {
+            let pos = *pos;
+            let (col_label, row_label) = grid_labels(pos);
+            let cell = game.board.cell(pos);
+            let owner = cell.classical.map(|mark| mark.player);
+            let tile_label = tile_label(owner);
+            let mark_label = match collapsible {
+                Some((a, b)) if pos == a || pos == b => "collapse-option",
+                _ if Some(pos) == game.pending => "selected-tile",
+                _ => "",
+            };
+            let msg = self.quantum_msg(pos);
+            let content = match cell.classical {
+                Some(mark) => self.mark_name(mark),
+                None if cell.spooky.is_empty() => self.mark_text(None),
+                None => {
+                    let names: Vec<String> = cell
+                        .spooky
+                        .iter()
+                        .map(|&mark| self.mark_name(mark))
+                        .collect();
+                    names.join(" ")
+                }
+            };
+            let label = self.cell_label(&content, pos);
+
+            let view_spooky = |mark: &quantum::Mark| {
+                html! {
+                    <span class="spooky-mark">{ self.mark_name(*mark) }</span>
+                }
+            };
+            let marks = match cell.classical {
+                Some(mark) => html! {
+                    <span class="classical-mark">
+                        { self.mark_name(mark) }
+                    </span>
+                },
+                None => html! {
+                    <span>{ for cell.spooky.iter().map(view_spooky) }</span>
+                },
+            };
+
+            html! {
+                <div
+                    class=(
+                        "tile",
+                        "quantum-tile",
+                        tile_label,
+                        mark_label,
+                        self.cursor_label(pos),
+                        col_label,
+                        row_label,
+                    )
+                    id=cell_id(pos)
+                    role="gridcell"
+                    aria-label=label
+                    onclick=move |_| msg.clone()
+                >
+                    { marks }
+                </div>
+            }
+        };
+
+        html! {
+            <div class="game-board">
+                { for view_rows(view_tile).into_iter() }
+            </div>
+        }
+    }
+
+    /// Describe the pending spooky move or collapse, or the final score.
+    fn quantum_notice(&self) -> String {
+        let game = &self.quantum;
+
+        if let Some(mark) = game.cycle {
+            return self.format(
+                "notice.collapse",
+                &[&self.side_name(game.turn), &self.mark_name(mark)],
+            );
+        }
+
+        if game.ongoing {
+            return match game.pending {
+                Some(_) => self.text("notice.entangle").to_string(),
+                None => String::new(),
+            };
+        }
+
+        self.format(
+            "notice.score",
+            &[
+                &self.side_name(Player::X),
+                &score_name(game.xscore),
+                &self.side_name(Player::O),
+                &score_name(game.oscore),
+            ],
+        )
+    }
+
+    /// Render the numerical board, colouring numbers by their owner.
+    fn view_numerical(&self) -> Html<Self> {
+        let board = &self.numerical.board;
+
+        let view_tile = |pos: &Position| {
+            let (col, row) = *pos;
+            let (col_label, row_label) = grid_labels(*pos);
+            let number = board.values[col][row];
+            let tile_label = tile_label(number.map(NumericalState::owner));
+            let text = number.map(|n| n.to_string()).unwrap_or_default();
+            let label = match number {
+                Some(_) => self.cell_label(&text, *pos),
+                None => self.cell_label(&self.mark_text(None), *pos),
+            };
+
+            html! {
+                <div
+                    class=(
+                        "tile",
+                        "number-tile",
+                        tile_label,
+                        self.cursor_label(*pos),
+                        col_label,
+                        row_label,
+                    )
+                    id=cell_id(*pos)
+                    role="gridcell"
+                    aria-label=label
+                    onclick=|_| Msg::NumberClick((col, row))
+                >
+                    { text }
+                </div>
+            }
+        };
+
+        html! {
+            <div class="game-board">
+                { for view_rows(view_tile).into_iter() }
+            </div>
+        }
+    }
+
+    /// Render each player's remaining numbers, letting the active player
+    /// pick one.
+    fn view_number_palette(&self) -> Html<Self> {
+        let game = &self.numerical;
+
+        let view_number = |number: u8| {
+            let selected = if game.ongoing && number == game.number {
+                "selected"
+            } else {
+                ""
+            };
+
+            html! {
+                <button
+                    type="button"
+                    class=("option-button", "number-button", selected)
+                    onclick=|_| Msg::SelectNumber(number)
+                >
+                    { number }
+                </button>
+            }
+        };
+        let view_palette = |player: Player| {
+            let active = if game.ongoing && player == game.turn {
+                "active-palette"
+            } else {
+                ""
+            };
+
+            html! {
+                <div class=("option-group", active)>
+                    { self.side_name(player) }
+                    { for game.remaining(player).into_iter().map(view_number) }
+                </div>
+            }
+        };
+
+        html! {
+            <div class="game-options">
+                { view_palette(Player::X) }
+                { view_palette(Player::O) }
+            </div>
+        }
+    }
+
+    /// Render the Notakto board count options.
+    fn view_notakto_options(&self) -> Html<Self> {
+        let current = self.notakto.boards.len();
+
+        let view_count = |&count: &usize| {
+            let label = self.plural("option.boards", count as u32);
+            view_option(&label, count == current, Msg::SetBoardCount(count))
+        };
+        let counts = [1, 2, 3];
+
+        html! {
+            <div class="game-options">
+                <div class="option-group">
+                    { for counts.iter().map(view_count) }
+                </div>
+            </div>
+        }
+    }
+
+    /// Explain how a Notakto game was lost.
+    fn notakto_notice(&self) -> String {
+        match self.notakto.winner {
+            Some(winner) => {
+                let loser = self.side_name(winner.other());
+                self.format("notice.killed", &[&loser])
+            }
+            None => String::new(),
+        }
+    }
+
+    /// Render the classic rule options, plus the mark picker in wild games.
+    fn view_classic_options(&self) -> Html<Self> {
+        let game = &self.classic;
+
+        let view_misere = |&(misere, label): &(bool, &'static str)| {
+            view_option(label, misere == game.misere, Msg::SetMisere(misere))
+        };
+        let view_wild = |&(wild, label): &(bool, &'static str)| {
+            view_option(label, wild == game.wild, Msg::SetWild(wild))
+        };
+        let view_vanishing = |&(vanishing, label): &(bool, &'static str)| {
+            let msg = Msg::SetVanishing(vanishing);
+            view_option(label, vanishing == game.vanishing, msg)
+        };
+        let view_morris = |option: &(bool, bool, &'static str)| {
+            let (morris, diagonal, label) = *option;
+            let selected = morris == game.morris && diagonal == game.diagonal;
+            view_option(label, selected, Msg::SetMorris(morris, diagonal))
+        };
+        let view_mark = |&mark: &Player| {
+            let label = self.place_label(mark);
+            view_option(&label, mark == game.mark, Msg::SelectMark(mark))
+        };
+        let view_series = |option: &(Option<SeriesFormat>, String)| {
+            let (format, label) = option;
+            let current = game.series.as_ref().map(|series| series.format);
+            view_option(label, *format == current, Msg::SetSeries(*format))
+        };
+        let view_control = |option: &(Option<TimeControl>, String)| {
+            let (control, label) = option;
+            let selected = *control == game.clock.control;
+            view_option(label, selected, Msg::SetTimeControl(*control))
+        };
+
+        let misere_options = [
+            (false, self.text("option.normal")),
+            (true, self.text("option.misere")),
+        ];
+        let wild_options = [
+            (false, self.text("option.standard")),
+            (true, self.text("option.wild")),
+        ];
+        let vanishing_options = [
+            (false, self.text("option.unlimited")),
+            (true, self.text("option.vanishing")),
+        ];
+        let morris_options = [
+            (false, false, self.text("option.placement")),
+            (true, false, self.text("option.morris")),
+            (true, true, self.text("option.morris_diagonal")),
+        ];
+        let series_formats = [
+            (None, self.text("option.single_games").to_string()),
+            (
+                Some(SeriesFormat::BestOf(3)),
+                self.format("option.best_of", &[&3]),
+            ),
+            (
+                Some(SeriesFormat::BestOf(5)),
+                self.format("option.best_of", &[&5]),
+            ),
+            (
+                Some(SeriesFormat::FirstTo(3)),
+                self.format("option.first_to", &[&3]),
+            ),
+        ];
+        let time_controls = [
+            (None, self.text("option.untimed").to_string()),
+            (
+                Some(TimeControl::new(1, 1, Bonus::Increment)),
+                String::from("1+1"),
+            ),
+            (
+                Some(TimeControl::new(3, 2, Bonus::Increment)),
+                String::from("3+2"),
+            ),
+            (
+                Some(TimeControl::new(5, 5, Bonus::Delay)),
+                self.format("option.delay", &[&5, &5]),
+            ),
+        ];
+        let marks = if game.wild {
+            vec![Player::X, Player::O]
+        } else {
+            Vec::new()
+        };
+
+        html! {
+            <div class="game-options">
+                <div class="option-group">
+                    { for misere_options.iter().map(view_misere) }
+                </div>
+                <div class="option-group">
+                    { for wild_options.iter().map(view_wild) }
+                </div>
+                <div class="option-group">
+                    { for vanishing_options.iter().map(view_vanishing) }
+                </div>
+                <div class="option-group">
+                    { for morris_options.iter().map(view_morris) }
+                </div>
+                <div class="option-group">
+                    { for time_controls.iter().map(view_control) }
+                </div>
+                <div class="option-group">
+                    { for series_formats.iter().map(view_series) }
+                </div>
+                <div class="option-group">
+                    { for marks.iter().map(view_mark) }
+                </div>
+            </div>
+        }
+    }
+
+    /// Describe how a misère or vanishing game ended.
+    fn classic_notice(&self) -> String {
+        let game = &self.classic;
+
+        match game.winner {
+            Some(winner)
+                if game.clock.control.is_some()
+                    && game.clock.remaining(winner.other()) == 0 =>
+            {
+                let loser = self.side_name(winner.other());
+                self.format("notice.timeout", &[&loser])
+            }
+            Some(winner) if game.misere => self.format(
+                "notice.misere_line",
+                &[&self.side_name(winner.other())],
+            ),
+            None if game.vanishing && !game.ongoing => {
+                self.format("notice.move_limit", &[&VANISHING_MOVE_LIMIT])
+            }
+            None if game.morris && !game.ongoing => {
+                self.text("notice.repetition").to_string()
+            }
+            None if game.must_shift() => {
+                self.format("notice.move_piece", &[&self.side_name(game.turn)])
+            }
+            None if game.vanishing => self.format(
+                "notice.move_count",
+                &[&(game.moves + 1), &VANISHING_MOVE_LIMIT],
+            ),
+            _ => String::new(),
+        }
+    }
+
+    /// Render the Order and Chaos symbol chooser.
+    fn view_order_chaos_options(&self) -> Html<Self> {
+        let current = self.order_chaos.symbol;
+
+        let view_symbol = |&symbol: &Player| {
+            let label = self.place_label(symbol);
+            view_option(&label, symbol == current, Msg::SelectMark(symbol))
+        };
+        let symbols = [Player::X, Player::O];
+
+        html! {
+            <div class="game-options">
+                <div class="option-group">
+                    { for symbols.iter().map(view_symbol) }
+                </div>
+            </div>
+        }
+    }
+
+    /// Render the score of the classic match, or its winner once decided.
+    fn view_series(&self) -> Html<Self> {
+        let series = match &self.classic.series {
+            Some(series) if self.mode == Mode::Classic => series,
+            _ => return html! { <div class="match-status"></div> },
+        };
+        let (xwins, owins) =
+            (series.score.wins(Player::X), series.score.wins(Player::O));
+
+        let text = match series.winner {
+            Some(winner) => self.format(
+                "series.won",
+                &[
+                    &self.side_name(winner),
+                    &xwins.max(owins),
+                    &xwins.min(owins),
+                ],
+            ),
+            None => self.format(
+                "series.score",
+                &[
+                    &self.series_name(series.format),
+                    &self.side_name(Player::X),
+                    &xwins,
+                    &self.side_name(Player::O),
+                    &owins,
+                ],
+            ),
+        };
+
+        html! {
+            <div class="match-status">{ text }</div>
+        }
+    }
+
+    /// Render both classic clocks, highlighting the one running.
+    fn view_clocks(&self) -> Html<Self> {
+        let game = &self.classic;
+
+        if game.clock.control.is_none() || self.mode != Mode::Classic {
+            return html! { <div class="game-clocks"></div> };
+        }
+
+        let view_clock = |player: Player| {
+            let running = if game.ongoing && player == game.turn {
+                "running-clock"
+            } else {
+                ""
+            };
+            let text = format!(
+                "{} {}",
+                self.side_name(player),
+                clock_time(game.clock.remaining(player))
+            );
+
+            html! {
+                <div class=("game-clock", running)>{ text }</div>
+            }
+        };
+
+        html! {
+            <div class="game-clocks">
+                { view_clock(Player::X) }
+                { view_clock(Player::O) }
+            </div>
+        }
+    }
+
+    /// Render the name, colour and glyph inputs of each player.
+    fn view_settings(&self) -> Html<Self> {
+        if !self.show_settings {
+            return html! { <div class="settings-panel"></div> };
+        }
+
+        let view_side = |player: &Player| {
+            let player = *player;
+            let side = self.settings.side(player);
+
+            html! {
+                <div class="settings-row">
+                    <input
+                        type="text"
+                        class="settings-name"
+                        value=&side.name
+                        placeholder=Settings::default_name(player)
+                        oninput=|e| Msg::SetName(player, e.value)
+                    />
+                    <input
+                        type="color"
+                        class="settings-colour"
+                        value=&side.colour
+                        oninput=|e| Msg::SetColour(player, e.value)
+                    />
+                    <input
+                        type="text"
+                        class="settings-glyph"
+                        value=&side.glyph
+                        oninput=|e| Msg::SetGlyph(player, e.value)
+                    />
+                </div>
+            }
+        };
+
+        let view_language = |&language: &Language| {
+            let selected = language == self.settings.language;
+            view_option(language.name(), selected, Msg::SetLanguage(language))
+        };
+        let view_theme = |&theme: &Theme| {
+            let selected = theme == self.settings.theme;
+            view_option(self.text(theme.key()), selected, Msg::SetTheme(theme))
+        };
+        let view_numbers = |&(numbers, label): &(bool, &str)| {
+            let selected = numbers == self.settings.move_numbers;
+            view_option(label, selected, Msg::SetMoveNumbers(numbers))
+        };
+        let number_options = [
+            (false, self.text("option.hide_numbers")),
+            (true, self.text("option.show_numbers")),
+        ];
+        let seats = &Player::ALL[..self.player_count()];
+
+        html! {
+            <div class="settings-panel">
+                <div class="option-group">
+                    { for Language::ALL.iter().map(view_language) }
+                </div>
+                <div class="option-group">
+                    { for Theme::ALL.iter().map(view_theme) }
+                </div>
+                <div class="option-group">
+                    { for number_options.iter().map(view_numbers) }
+                </div>
+                { for seats.iter().map(view_side) }
+                <button
+                    type="button"
+                    class="option-button"
+                    onclick=|_| Msg::ResetSettings
+                >
+                    { self.text("button.defaults") }
+                </button>
+            </div>
+        }
+    }
+
+    /// Render seating, per-entity totals and head-to-head results.
+    fn view_stats(&self) -> Html<Self> {
+        if !self.show_stats {
+            return html! { <div class="stats-panel"></div> };
+        }
+
+        let profiles = &self.profiles;
+
+        // Seating, with guests going unrecorded and strategies only
+        // playing the classic board
+        let view_seat = |seat: &Player| {
+            let seat = *seat;
+            let seated = profiles.seated(seat);
+            let view_entity = |entity: Option<Entity>| {
+                let label = match &entity {
+                    Some(entity) => self.entity_name(entity).to_string(),
+                    None => self.text("button.guest").to_string(),
+                };
+                let selected = entity.as_ref() == seated;
+                view_option(&label, selected, Msg::SeatProfile(seat, entity))
+            };
+            let strategies: &[Strategy] = if self.mode == Mode::Classic {
+                &Strategy::ALL
+            } else {
+                &[]
+            };
+
+            html! {
+                <div class="option-group">
+                    <span class="seat-name">{ self.side_name(seat) }</span>
+                    { view_entity(None) }
+                    { for profiles.names.iter().map(|name| {
+                        view_entity(Some(Entity::Profile(name.clone())))
+                    }) }
+                    { for strategies.iter().map(|&strategy| {
+                        view_entity(Some(Entity::Strategy(strategy)))
+                    }) }
+                </div>
+            }
+        };
+
+        // Leaderboard, ordered by rating
+        let mut entities = profiles.entities();
+        entities.sort_by_key(|entity| {
+            std::cmp::Reverse(profiles.rating(entity))
+        });
+
+        let rate = |tally: profiles::Tally| match tally.win_rate() {
+            Some(rate) => format!("{}%", rate),
+            None => String::from("-"),
+        };
+        let view_totals = |entity: &Entity| {
+            let totals = profiles.totals(entity);
+            let streak = match totals.streak {
+                Some((outcome, count)) => self.plural(outcome.key(), count),
+                None => String::from("-"),
+            };
+
+            html! {
+                <tr>
+                    <td>{ self.entity_name(entity) }</td>
+                    <td>{ profiles.rating(entity) }</td>
+                    <td class="rating-chart">
+                        { view_rating_chart(&profiles.rating_history(entity)) }
+                    </td>
+                    <td>{ totals.overall.played() }</td>
+                    <td>{ totals.overall.wins }</td>
+                    <td>{ totals.overall.losses }</td>
+                    <td>{ totals.overall.draws }</td>
+                    <td>{ rate(totals.first) }</td>
+                    <td>{ rate(totals.second) }</td>
+                    <td>{ streak }</td>
+                </tr>
+            }
+        };
+
+        // Head-to-head, as wins-losses-draws of each row against each column
+        let view_rivals = |entity: &Entity| {
+            let view_cell = |rival: &Entity| {
+                let text = if entity == rival {
+                    String::from("-")
+                } else {
+                    let tally = profiles.head_to_head(entity, rival);
+                    format!(
+                        "{}-{}-{}",
+                        tally.wins, tally.losses, tally.draws
+                    )
+                };
+
+                html! { <td>{ text }</td> }
+            };
+
+            html! {
+                <tr>
+                    <th>{ self.entity_name(entity) }</th>
+                    { for entities.iter().map(view_cell) }
+                </tr>
+            }
+        };
+
+        let view_header = |entity: &Entity| {
+            html! { <th>{ self.entity_name(entity) }</th> }
+        };
+        let leaderboard = html! {
+            <table class="stats-table">
+                <tr>
+                    <th>{ self.text("stats.player") }</th>
+                    <th>{ self.text("stats.rating") }</th>
+                    <th>{ self.text("stats.progress") }</th>
+                    <th>{ self.text("stats.games") }</th>
+                    <th>{ self.text("stats.wins") }</th>
+                    <th>{ self.text("stats.losses") }</th>
+                    <th>{ self.text("stats.draws") }</th>
+                    <th>{ self.text("stats.first") }</th>
+                    <th>{ self.text("stats.second") }</th>
+                    <th>{ self.text("stats.streak") }</th>
+                </tr>
+                { for entities.iter().map(view_totals) }
+            </table>
+        };
+        let rivalries = html! {
+            <table class="stats-table">
+                <tr>
+                    <th></th>
+                    { for entities.iter().map(view_header) }
+                </tr>
+                { for entities.iter().map(view_rivals) }
+            </table>
+        };
+        let seats = &Player::ALL[..self.player_count()];
+
+        // Saved profiles, for the command-line rankings
+        let export: Text = Json(profiles).into();
+        let export = json_data_url(&export.unwrap_or_default());
+
+        html! {
+            <div class="stats-panel">
+                { for seats.iter().map(view_seat) }
+                <div class="option-group">
+                    <input
+                        type="text"
+                        class="profile-name"
+                        value=&self.draft
+                        oninput=|e| Msg::SetDraft(e.value)
+                    />
+                    <button
+                        type="button"
+                        class="option-button"
+                        onclick=|_| Msg::AddProfile
+                    >
+                        { self.text("button.add_profile") }
+                    </button>
+                    <a
+                        class="option-button"
+                        download=EXPORT_FILE
+                        href=export
+                    >
+                        { self.text("button.export") }
+                    </a>
+                </div>
+                { leaderboard }
+                { rivalries }
+            </div>
+        }
+    }
+
+    /// Render the connect-four rule options.
+    fn view_connect_four_options(&self) -> Html<Self> {
+        let current = self.connect_four.rules;
+        let view_rules = |option: &(Rules, &'static str)| {
+            view_rule_option(current, option)
+        };
+
+        let size_options = [
+            (Rules { width: 7, height: 6, ..current }, "7x6"),
+            (Rules { width: 8, height: 7, ..current }, "8x7"),
+            (Rules { width: 9, height: 7, ..current }, "9x7"),
+        ];
+
+        html! {
+            <div class="game-options">
+                <div class="option-group">
+                    { for size_options.iter().map(view_rules) }
+                </div>
+                { self.view_wrap_options(current) }
+            </div>
+        }
+    }
+
+    /// Render the multiplayer rule options.
+    fn view_multiplayer_options(&self) -> Html<Self> {
+        let current = self.multiplayer.rules;
+        let view_rules = |option: &(Rules, &'static str)| {
+            view_rule_option(current, option)
+        };
+        let view_players = |&players: &usize| {
+            let rules = Rules { players, ..current };
+            let label = self.format("option.players", &[&players]);
+            view_option(&label, rules == current, Msg::SetRules(rules))
+        };
+
+        let player_options = [3, 4];
+        let size_options = [
+            (Rules { width: 8, height: 8, ..current }, "8x8"),
+            (Rules { width: 10, height: 10, ..current }, "10x10"),
+        ];
+
+        html! {
+            <div class="game-options">
+                <div class="option-group">
+                    { for player_options.iter().map(view_players) }
+                </div>
+                <div class="option-group">
+                    { for size_options.iter().map(view_rules) }
+                </div>
+                { self.view_wrap_options(current) }
+            </div>
+        }
+    }
+
+    /// Render the gomoku rule options.
+    fn view_gomoku_options(&self) -> Html<Self> {
+        let current = self.gomoku.rules;
+        let view_rules = |option: &(Rules, &'static str)| {
+            view_rule_option(current, option)
+        };
+
+        let size_options = [
+            (Rules { width: 15, height: 15, ..current }, "15x15"),
+            (Rules { width: 19, height: 19, ..current }, "19x19"),
+        ];
+        let overline_options = [
+            (
+                Rules { exact: false, renju: false, ..current },
+                self.text("option.freestyle"),
+            ),
+            (
+                Rules { exact: true, renju: false, ..current },
+                self.text("option.standard"),
+            ),
+            (
+                Rules { exact: false, renju: true, ..current },
+                self.text("option.renju"),
+            ),
+        ];
+        let opening_options = [
+            (
+                Rules { opening: Opening::Free, ..current },
+                self.text("option.free"),
+            ),
+            (
+                Rules { opening: Opening::Swap, ..current },
+                self.text("option.swap"),
+            ),
+            (
+                Rules { opening: Opening::Swap2, ..current },
+                self.text("option.swap2"),
+            ),
+        ];
+
+        // Colour choice rendering, only while an opening awaits it
+        let view_choice = |&(choice, label): &(Choice, &'static str)| {
+            view_option(label, false, Msg::Choose(choice))
+        };
+        let choices: Vec<(Choice, &'static str)> = match self.gomoku.phase {
+            Phase::Choosing { extend } if self.gomoku.ongoing => {
+                let mut choices = vec![
+                    (Choice::Black, self.text("option.black")),
+                    (Choice::White, self.text("option.white")),
+                ];
+                if extend {
+                    let label = self.text("option.place_two");
+                    choices.push((Choice::Extend, label));
+                }
+                choices
+            }
+            _ => Vec::new(),
+        };
+
+        html! {
+            <div class="game-options">
+                <div class="option-group">
+                    { for size_options.iter().map(view_rules) }
+                </div>
+                <div class="option-group">
+                    { for overline_options.iter().map(view_rules) }
+                </div>
+                <div class="option-group">
+                    { for opening_options.iter().map(view_rules) }
+                </div>
+                { self.view_wrap_options(current) }
+                <div class="option-group">
+                    { for choices.iter().map(view_choice) }
+                </div>
+            </div>
+        }
+    }
+
+    /// Describe the opening and rule state of the gomoku game, if relevant.
+    fn gomoku_notice(&self) -> String {
+        let game = &self.gomoku;
+
+        if !game.ongoing {
+            return String::new();
+        }
+
+        if let Some(foul) = game.foul {
+            let foul = self.text(foul.key());
+            return self.format("notice.forbidden", &[&foul]);
+        }
+
+        let seat = self.side_name(game.turn);
+
+        match &game.phase {
+            Phase::Placing(colours) => {
+                let colour = self.text(colour_key(colours[0]));
+                self.format("notice.place_colour", &[&seat, &colour])
+            }
+            Phase::Choosing { .. } => {
+                self.format("notice.choose_colour", &[&seat])
+            }
+            Phase::Main if game.rules.opening != Opening::Free => self.format(
+                "notice.colours",
+                &[
+                    &self.side_name(Player::X),
+                    &self.text(colour_key(game.colour(Player::X))),
+                    &self.side_name(Player::O),
+                    &self.text(colour_key(game.colour(Player::O))),
+                ],
+            ),
+            Phase::Main => String::new(),
+        }
+    }
+}
+
+impl Renderable<Model> for Model {
+    fn view(&self) -> Html<Self> {
+        // CSS labels
+        let neutr_label = "neutral";
+
+        let (ongoing, turn, winner, log) = self.status();
+
+        // Game status rendering
+        let (game_status, indicator) = {
+            if ongoing {
+                let name = self.side_name(turn);
+                (self.format("status.active", &[&name]), team_label(turn))
+            } else {
+                match winner {
+                    Some(seat) => {
+                        let name = self.side_name(seat);
+                        let status = self.format("status.wins", &[&name]);
+                        (status, team_label(seat))
+                    }
+                    None => {
+                        (self.text("status.draw").to_string(), neutr_label)
+                    }
+                }
+            }
+        };
+
+        // Board rendering
+        let board = match self.mode {
+            Mode::Classic => self.view_classic(),
+            Mode::Ultimate => self.view_ultimate(),
+            Mode::Qubic => self.view_qubic(),
+            Mode::Gomoku => self.view_grid(&self.gomoku.grid),
+            Mode::ConnectFour => self.view_grid(&self.connect_four.grid),
+            Mode::Notakto => self.view_notakto(),
+            Mode::Quantum => self.view_quantum(),
+            Mode::OrderChaos => self.view_grid(&self.order_chaos.grid),
+            Mode::Numerical => self.view_numerical(),
+            Mode::Multiplayer => self.view_grid(&self.multiplayer.grid),
+        };
+
+        // Rule options rendering
+        let (options, notice) = match self.mode {
+            Mode::Classic => {
+                (self.view_classic_options(), self.classic_notice())
+            }
+            Mode::Gomoku => (self.view_gomoku_options(), self.gomoku_notice()),
+            Mode::ConnectFour => {
+                (self.view_connect_four_options(), String::new())
+            }
+            Mode::Notakto => {
+                (self.view_notakto_options(), self.notakto_notice())
+            }
+            Mode::Quantum => (
+                html! { <div class="game-options"></div> },
+                self.quantum_notice(),
+            ),
+            Mode::OrderChaos => {
+                (self.view_order_chaos_options(), String::new())
+            }
+            Mode::Numerical => (self.view_number_palette(), String::new()),
+            Mode::Multiplayer => {
+                (self.view_multiplayer_options(), String::new())
+            }
+            _ => (html! { <div class="game-options"></div> }, String::new()),
+        };
+
+        // Record rendering, one entry per player plus draws
+        let seats = &Player::ALL[..self.player_count()];
+        let entry_style = format!("width: {}%;", 99 / (seats.len() + 1));
+        let crowded = if seats.len() > 2 { "crowded" } else { "" };
+        let view_wins = |seat: &Player| {
+            let name = self.side_name(*seat);
+            let wins = log.wins(*seat);
+            let text = self.format("record.victories", &[&name, &wins]);
+
+            html! {
+                <div class=("record-entry", crowded) style=&entry_style>
+                    { text }
+                </div>
             }
         };
 
-        // Single-tile rendering
-        let view_tile = |pos: &Position| {
-            let (col, row) = *pos;
+        // Move numbers are drawn by the stylesheet, only when enabled
+        let numbers_label = if self.settings.move_numbers {
+            "move-numbers"
+        } else {
+            ""
+        };
 
-            let col_label = match col {
-                0 => "col-0",
-                1 => "col-1",
-                _ => "col-2",
-            };
-            let row_label = match row {
-                0 => "row-0",
-                1 => "row-1",
-                _ => "row-2",
-            };
-            let tile_label = match self.board.values[col][row] {
-                Some(Player::X) => "x-tile",
-                Some(Player::O) => "o-tile",
-                _ => "empty-tile",
-            };
+        // Mode selection rendering
+        let view_mode = |(mode, label): &(Mode, &'static str)| {
+            let mode = *mode;
+            let selected = if mode == self.mode { "selected" } else { "" };
 
             html! {
-                <div
-                    class=("tile", tile_label, col_label, row_label)
-                    onclick=|_| Msg::Click((col, row))
+                <button
+                    type="button"
+                    class=("mode-button", selected)
+                    onclick=|_| Msg::SetMode(mode)
                 >
-                </div>
+                    { self.text(label) }
+                </button>
             }
         };
-
-        // Complete rendering
-        let positions: [Position; 9] = [
-            (0, 0), (1, 0), (2, 0),
-            (0, 1), (1, 1), (2, 1),
-            (0, 2), (1, 2), (2, 2),
+        let modes = [
+            (Mode::Classic, "mode.classic"),
+            (Mode::Ultimate, "mode.ultimate"),
+            (Mode::Qubic, "mode.qubic"),
+            (Mode::Gomoku, "mode.gomoku"),
+            (Mode::ConnectFour, "mode.connect_four"),
+            (Mode::Notakto, "mode.notakto"),
+            (Mode::Quantum, "mode.quantum"),
+            (Mode::OrderChaos, "mode.order_chaos"),
+            (Mode::Numerical, "mode.numerical"),
+            (Mode::Multiplayer, "mode.multiplayer"),
         ];
 
+        // Complete rendering
         html! {
-            <div>
+            <div class=("app", self.settings.theme.class(), numbers_label)>
+                <style>{ self.settings.stylesheet() }</style>
                 <section class="game-container">
+                    <div class="game-modes">
+                        { for modes.iter().map(view_mode) }
+                    </div>
+                    { options }
                     <div class="record">
-                        <div class="col-0">
-                            { format!("Chi victories: {}", self.log.xwins) }
-                        </div>
-                        <div class="col-1">
-                            { format!("Omi victories: {}", self.log.owins) }
-                        </div>
-                        <div class="col-2">
-                            { format!("Draws: {}", self.log.draws) }
+                        { for seats.iter().map(view_wins) }
+                        <div
+                            class=("record-entry", crowded)
+                            style=&entry_style
+                        >
+                            { self.format("record.draws", &[&log.draws]) }
                         </div>
                     </div>
                     <section class="game-area">
-                        <div class="game-board">
-                            { for positions.iter().map(view_tile) }
+                        <div
+                            class="board-focus"
+                            role="grid"
+                            tabindex="0"
+                            aria-label=self.text("aria.board")
+                            aria-activedescendant=cell_id(self.cursor)
+                            onkeydown=|e| {
+                                // Keep arrows and space from scrolling
+                                let key = e.key();
+                                if key.starts_with("Arrow") || key == " " {
+                                    e.prevent_default();
+                                }
+                                Msg::Key(key)
+                            }
+                        >
+                            { board }
                         </div>
-                        <div class="game-status">
+                        <div
+                            class="game-status"
+                            role="status"
+                            aria-live="polite"
+                        >
                             { game_status }
+                            <span class="visually-hidden">
+                                { &self.announcement }
+                            </span>
+                        </div>
+                        { self.view_series() }
+                        { self.view_clocks() }
+                        <div class="game-notice" aria-live="polite">
+                            { notice }
                         </div>
                         <div class=("indicator", indicator)>
                         </div>
@@ -270,10 +2943,33 @@ impl Renderable<Model> for Model {
                                 onclick=|_| Msg::Reset
                             >
                                 {
-                                    "RESET"
+                                    self.text("button.reset")
+                                }
+                            </button>
+                            <button
+                                type="button"
+                                class="reset-button"
+                                onclick=|_| Msg::ToggleSettings
+                            >
+                                {
+                                    self.text("button.settings")
+                                }
+                            </button>
+                            <button
+                                type="button"
+                                class="reset-button"
+                                onclick=|_| Msg::ToggleStats
+                            >
+                                {
+                                    self.text("button.stats")
                                 }
                             </button>
                         </div>
+                        <div class="keyboard-help">
+                            { self.text("notice.keys") }
+                        </div>
+                        { self.view_settings() }
+                        { self.view_stats() }
                     </section>
                 </section>
             </div>
@@ -480,4 +3176,390 @@ mod tests {
         assert_eq!(game.play(plays[16]), MoveOutcome::NoChange);
         assert_eq!(game.play(plays[17]), MoveOutcome::Draw);
     }
+
+    #[test]
+    fn test_misere_line_loses_for_x() {
+        let mut game = GameState::new();
+        let plays = [
+            (0, 0), // X
+            (1, 0), // O
+            (0, 1),
+            (1, 1),
+            (0, 2),
+        ];
+
+        //  X   O   .
+        //  X   O   .
+        //  X   .   .
+
+        game.set_misere(true);
+
+        assert_eq!(game.play(plays[0]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[1]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[2]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[3]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[4]), MoveOutcome::Lose(Player::X));
+        assert_eq!(game.winner, Some(Player::O));
+        assert_eq!(game.log.wins(Player::O), 1);
+        assert_eq!(game.log.wins(Player::X), 0);
+    }
+
+    #[test]
+    fn test_misere_line_loses_for_o() {
+        let mut game = GameState::new();
+        let plays = [
+            (0, 0), // X
+            (1, 0), // O
+            (0, 1),
+            (1, 1),
+            (2, 2),
+            (1, 2),
+        ];
+
+        //  X   O   .
+        //  X   O   .
+        //  .   O   X
+
+        game.set_misere(true);
+
+        assert_eq!(game.play(plays[0]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[1]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[2]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[3]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[4]), MoveOutcome::Switch);
+        assert_eq!(game.play(plays[5]), MoveOutcome::Lose(Player::O));
+        assert_eq!(game.winner, Some(Player::X));
+        assert_eq!(game.log.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_misere_draw() {
+        let mut game = GameState::new();
+        let plays = [
+            (0, 0), // X
+            (1, 0), // O
+            (2, 0),
+            (2, 1),
+            (0, 1),
+            (0, 2),
+            (1, 1),
+            (2, 2),
+            (1, 2),
+        ];
+
+        //  X   O   X
+        //  X   X   O
+        //  O   X   O
+
+        game.set_misere(true);
+
+        for &pos in plays[..8].iter() {
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+
+        assert_eq!(game.play(plays[8]), MoveOutcome::Draw);
+        assert_eq!(game.log.draws, 1);
+    }
+
+    #[test]
+    fn test_wild_mover_owns_line() {
+        let mut game = GameState::new();
+
+        //  O   X   .
+        //  O   X   .
+        //  O   .   .
+
+        game.set_wild(true);
+
+        game.select_mark(Player::O);
+        assert_eq!(game.play((0, 0)), MoveOutcome::Switch);
+        game.select_mark(Player::X);
+        assert_eq!(game.play((1, 0)), MoveOutcome::Switch);
+        game.select_mark(Player::O);
+        assert_eq!(game.play((0, 1)), MoveOutcome::Switch);
+        game.select_mark(Player::X);
+        assert_eq!(game.play((1, 1)), MoveOutcome::Switch);
+
+        // X's seat completes the line of O marks and wins
+        game.select_mark(Player::O);
+        assert_eq!(game.play((0, 2)), MoveOutcome::Win(Player::X));
+        assert_eq!(game.board.values[0][2], Some(Player::O));
+        assert_eq!(game.winner, Some(Player::X));
+        assert_eq!(game.log.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_wild_misere_mover_loses() {
+        let mut game = GameState::new();
+
+        game.set_wild(true);
+        game.set_misere(true);
+        assert!(game.wild);
+
+        game.select_mark(Player::X);
+        assert_eq!(game.play((0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play((1, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play((2, 2)), MoveOutcome::Switch);
+
+        // O's seat completes a line of X marks and loses
+        assert_eq!(game.play((2, 0)), MoveOutcome::Lose(Player::O));
+        assert_eq!(game.winner, Some(Player::X));
+    }
+
+    #[test]
+    fn test_vanishing_removes_oldest_mark() {
+        let mut game = GameState::new();
+        let plays = [
+            (0, 0), // X
+            (1, 0), // O
+            (2, 2),
+            (1, 1),
+            (2, 1),
+            (0, 2),
+        ];
+
+        //  X   O   .
+        //  .   O   X
+        //  O   .   X
+
+        game.set_vanishing(true);
+
+        for &pos in plays.iter() {
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+
+        assert_eq!(game.next_to_vanish(), Some((0, 0)));
+
+        // X's fourth mark removes the one in the corner
+        assert_eq!(game.play((1, 2)), MoveOutcome::Switch);
+        assert_eq!(game.board.values[0][0], None);
+        assert_eq!(game.numbers[0][0], None);
+        assert_eq!(game.board.values[1][2], Some(Player::X));
+        assert_eq!(game.next_to_vanish(), Some((1, 0)));
+
+        // O's fourth mark removes (1, 0), so the column is not completed
+        assert_eq!(game.play((1, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.board.values[1][0], None);
+    }
+
+    #[test]
+    fn test_vanishing_win() {
+        let mut game = GameState::new();
+        let plays = [
+            (0, 0), // X
+            (1, 0), // O
+            (2, 0),
+            (1, 1),
+            (0, 2),
+            (2, 2),
+            (0, 1),
+        ];
+
+        //  X   O   X
+        //  X   O   .
+        //  X   .   O
+
+        game.set_vanishing(true);
+
+        for &pos in plays[..6].iter() {
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+
+        // (0, 0) vanishes as (0, 1) is placed, leaving no line
+        assert_eq!(game.play(plays[6]), MoveOutcome::Switch);
+        assert_eq!(game.board.values[0][0], None);
+
+        // O's oldest mark at (1, 0) vanishes, X then completes the column
+        assert_eq!(game.play((1, 2)), MoveOutcome::Switch);
+        assert_eq!(game.play((0, 0)), MoveOutcome::Win(Player::X));
+    }
+
+    #[test]
+    fn test_vanishing_move_limit() {
+        let mut game = GameState::new();
+        let cycle = [
+            (0, 0), // X
+            (2, 0), // O
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (1, 2),
+            (2, 1),
+            (2, 2),
+        ];
+
+        //  X   X   O
+        //  X   O   X
+        //  .   O   O
+
+        game.set_vanishing(true);
+
+        for n in 0..(VANISHING_MOVE_LIMIT - 1) {
+            let pos = cycle[n as usize % cycle.len()];
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+
+        let last = cycle[(VANISHING_MOVE_LIMIT - 1) as usize % cycle.len()];
+
+        assert_eq!(game.play(last), MoveOutcome::Draw);
+        assert_eq!(game.log.draws, 1);
+    }
+
+    fn place_morris_pieces(game: &mut GameState) {
+        let plays = [
+            (0, 0), // X
+            (1, 0), // O
+            (1, 1),
+            (2, 2),
+            (0, 2),
+            (2, 0),
+        ];
+
+        //  X   O   O
+        //  .   X   .
+        //  X   .   O
+
+        for &pos in plays.iter() {
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+    }
+
+    #[test]
+    fn test_morris_win_by_moving() {
+        let mut game = GameState::new();
+
+        game.set_morris(true, false);
+        place_morris_pieces(&mut game);
+
+        // X has placed all three pieces and must move one instead
+        assert!(game.must_shift());
+        assert_eq!(game.play((0, 1)), MoveOutcome::NoChange);
+        assert_eq!(game.shift((1, 0), (0, 1)), MoveOutcome::NoChange);
+        assert_eq!(game.shift((0, 0), (2, 1)), MoveOutcome::NoChange);
+        assert_eq!(game.shift((1, 1), (0, 1)), MoveOutcome::Win(Player::X));
+        assert_eq!(game.board.values[1][1], None);
+    }
+
+    #[test]
+    fn test_move_numbers() {
+        let mut game = GameState::new();
+
+        assert_eq!(game.last_move(), None);
+
+        game.set_morris(true, false);
+        place_morris_pieces(&mut game);
+
+        assert_eq!(game.numbers[1][1], Some(3));
+        assert_eq!(game.last_move(), Some((2, 0)));
+
+        // Moving a piece carries the new move number with it
+        game.shift((1, 1), (1, 2));
+        assert_eq!(game.numbers[1][1], None);
+        assert_eq!(game.numbers[1][2], Some(7));
+        assert_eq!(game.last_move(), Some((1, 2)));
+
+        game.reset();
+        assert_eq!(game.last_move(), None);
+    }
+
+    #[test]
+    fn test_morris_adjacency() {
+        let mut game = GameState::new();
+
+        game.set_morris(true, false);
+
+        assert!(game.is_adjacent((1, 1), (1, 0)));
+        assert!(!game.is_adjacent((1, 1), (2, 2)));
+        assert!(!game.is_adjacent((0, 0), (0, 2)));
+
+        game.set_morris(true, true);
+
+        assert!(game.is_adjacent((1, 1), (2, 2)));
+        assert!(!game.is_adjacent((0, 0), (2, 2)));
+    }
+
+    #[test]
+    fn test_morris_threefold_repetition() {
+        let mut game = GameState::new();
+        let shuffle = [
+            ((1, 1), (2, 1)), // X
+            ((2, 2), (1, 2)), // O
+            ((2, 1), (1, 1)),
+            ((1, 2), (2, 2)),
+        ];
+
+        game.set_morris(true, false);
+        place_morris_pieces(&mut game);
+
+        for &(from, to) in shuffle.iter() {
+            assert_eq!(game.shift(from, to), MoveOutcome::Switch);
+        }
+        for &(from, to) in shuffle[..3].iter() {
+            assert_eq!(game.shift(from, to), MoveOutcome::Switch);
+        }
+
+        let (from, to) = shuffle[3];
+
+        assert_eq!(game.shift(from, to), MoveOutcome::Draw);
+        assert_eq!(game.log.draws, 1);
+    }
+
+    #[test]
+    fn test_clock_runs_for_player_to_move() {
+        let mut game = GameState::new();
+
+        game.set_time_control(Some(TimeControl::new(1, 2, Bonus::Increment)));
+
+        assert_eq!(game.tick(10_000), MoveOutcome::NoChange);
+        assert_eq!(game.play((1, 1)), MoveOutcome::Switch);
+        assert_eq!(game.tick(1_000), MoveOutcome::NoChange);
+
+        assert_eq!(game.clock.remaining(Player::X), 52_000);
+        assert_eq!(game.clock.remaining(Player::O), 59_000);
+    }
+
+    #[test]
+    fn test_flag_fall_loses() {
+        let mut game = GameState::new();
+
+        game.set_time_control(Some(TimeControl::new(1, 0, Bonus::Delay)));
+
+        assert_eq!(game.play((1, 1)), MoveOutcome::Switch);
+        assert_eq!(game.tick(60_000), MoveOutcome::Timeout(Player::O));
+        assert_eq!(game.winner, Some(Player::X));
+        assert_eq!(game.log.wins(Player::X), 1);
+
+        // Clocks stop with the game
+        assert_eq!(game.tick(1_000), MoveOutcome::NoChange);
+        assert_eq!(game.clock.remaining(Player::X), 60_000);
+    }
+
+    #[test]
+    fn test_match_alternates_first_player() {
+        let mut game = GameState::new();
+        let plays = [
+            (0, 0), // X
+            (0, 1), // O
+            (1, 0),
+            (1, 1),
+        ];
+
+        game.set_series(Some(SeriesFormat::FirstTo(2)));
+
+        for &pos in plays.iter() {
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+        assert_eq!(game.play((2, 0)), MoveOutcome::Win(Player::X));
+
+        game.reset();
+
+        assert_eq!(game.turn, Player::O);
+        assert_eq!(game.series.as_ref().unwrap().score.wins(Player::X), 1);
+
+        // Abandoning a game keeps the same starter
+        assert_eq!(game.play((1, 1)), MoveOutcome::Switch);
+        game.reset();
+        assert_eq!(game.turn, Player::O);
+    }
 }
@@ -0,0 +1,973 @@
+//! Generalized m,n,k-games: k in a row on an m by n grid.
+
+use super::*;
+
+use renju::Foul;
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Line directions as (column, row) steps: horizontal, vertical, and the two
+/// diagonals. Their opposites are covered by walking each line both ways.
+pub(crate) const DIRECTIONS: [(isize, isize); 4] =
+    [(1, 0), (0, 1), (1, 1), (1, -1)];
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// Opening protocols deciding who plays which colour.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Opening {
+    /// X simply moves first.
+    Free,
+    /// The first seat places black, white, black, then the second seat
+    /// chooses a colour.
+    Swap,
+    /// As `Swap`, but the second seat may instead place one more stone of
+    /// each colour and hand the choice back.
+    Swap2,
+}
+
+/// Colour choices offered during an opening protocol.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Choice {
+    Black,
+    White,
+    Extend,
+}
+
+/// One step of a game, in the order the game was played.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Action {
+    /// A stone placed in the given cell, after any fall under gravity.
+    Place(Position),
+    /// A colour chosen during an opening protocol.
+    Choose(Choice),
+}
+
+/// Stage of a game.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Phase {
+    /// The active seat places stones of the listed colours, in order.
+    Placing(Vec<Player>),
+    /// The active seat picks a colour, or extends the opening if allowed.
+    Choosing { extend: bool },
+    /// Normal alternating play.
+    Main,
+}
+
+/// Configuration of an m,n,k-game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Rules {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) k: usize,
+    /// Only lines of exactly `k` win; longer "overlines" don't count.
+    pub(crate) exact: bool,
+    /// Forbid double-threes, double-fours and overlines for black.
+    pub(crate) renju: bool,
+    pub(crate) opening: Opening,
+    /// Pieces drop to the lowest empty cell of the chosen column.
+    pub(crate) gravity: bool,
+    /// Lines wrap around the edges, as on a torus.
+    pub(crate) wrap: bool,
+    /// Players taking turns in order, each with their own mark.
+    pub(crate) players: usize,
+}
+
+impl Rules {
+    /// Freestyle gomoku on a 15x15 board, where five or more wins.
+    pub(crate) fn gomoku() -> Self {
+        Self {
+            width: 15,
+            height: 15,
+            k: 5,
+            exact: false,
+            renju: false,
+            opening: Opening::Free,
+            gravity: false,
+            wrap: false,
+            players: 2,
+        }
+    }
+
+    /// Connect-four on a 7 wide by 6 tall board, where four or more wins.
+    pub(crate) fn connect_four() -> Self {
+        Self {
+            width: 7,
+            height: 6,
+            k: 4,
+            exact: false,
+            renju: false,
+            opening: Opening::Free,
+            gravity: true,
+            wrap: false,
+            players: 2,
+        }
+    }
+
+    /// Three players taking turns on an 8x8 board, where four or more wins.
+    pub(crate) fn multiplayer() -> Self {
+        Self {
+            width: 8,
+            height: 8,
+            k: 4,
+            exact: false,
+            renju: false,
+            opening: Opening::Free,
+            gravity: false,
+            wrap: false,
+            players: 3,
+        }
+    }
+
+    /// Check whether a line of `length` wins for stones of `colour`.
+    ///
+    /// Under renju rules black needs exactly `k`, while white may overline.
+    pub(crate) fn is_win(&self, length: usize, colour: Player) -> bool {
+        let exact = if self.renju {
+            colour == Player::X
+        } else {
+            self.exact
+        };
+
+        if exact {
+            length == self.k
+        } else {
+            length >= self.k
+        }
+    }
+
+    /// Empty grid for a new game under these rules.
+    fn empty_grid(&self) -> Grid {
+        Grid {
+            wrap: self.wrap,
+            ..Grid::new(self.width, self.height)
+        }
+    }
+
+    /// Phase a new game starts in.
+    fn first_phase(&self) -> Phase {
+        match self.opening {
+            Opening::Free => Phase::Main,
+            _ => Phase::Placing(vec![Player::X, Player::O, Player::X]),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// Rectangular board of arbitrary size.
+#[derive(Clone, Debug)]
+pub(crate) struct Grid {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    /// Stepping off an edge re-enters from the opposite one.
+    pub(crate) wrap: bool,
+    pub(crate) values: Vec<Vec<Option<Player>>>,
+}
+
+impl Grid {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a new grid with each cell instantiated to `None`.
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            wrap: false,
+            values: vec![vec![None; height]; width],
+        }
+    }
+
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Value of the cell at the given position, or `None` when out of bounds.
+    pub(crate) fn get(&self, (col, row): Position) -> Option<Player> {
+        self.values
+            .get(col)
+            .and_then(|column| column.get(row))
+            .cloned()
+            .flatten()
+    }
+
+    /// Check whether a position lies on the grid.
+    pub(crate) fn contains(&self, (col, row): Position) -> bool {
+        col < self.width && row < self.height
+    }
+
+    /// Lowest empty cell of a column, if it has room left.
+    pub(crate) fn landing(&self, col: usize) -> Option<Position> {
+        let column = self.values.get(col)?;
+
+        column.iter().rposition(Option::is_none).map(|row| (col, row))
+    }
+
+    /// Step from a position, staying on the grid, or wrapping around it on a
+    /// toroidal grid.
+    pub(crate) fn step(
+        &self,
+        (col, row): Position,
+        (dc, dr): (isize, isize),
+    ) -> Option<Position> {
+        let col = col as isize + dc;
+        let row = row as isize + dr;
+
+        if self.wrap {
+            let col = col.rem_euclid(self.width as isize) as usize;
+            let row = row.rem_euclid(self.height as isize) as usize;
+
+            return Some((col, row));
+        }
+
+        if col < 0 || row < 0 {
+            return None;
+        }
+
+        let pos = (col as usize, row as usize);
+
+        if self.contains(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    //////////////////////////////////
+    // Evaluation
+    //////////////////////////////////
+
+    /// Length of the run of `pos`'s owner passing through `pos` along the
+    /// given direction, counting both ways. Empty cells have no run.
+    ///
+    /// On a toroidal grid, a run that wraps all the way around counts each
+    /// of its cells once.
+    pub(crate) fn run_length(
+        &self,
+        pos: Position,
+        dir: (isize, isize),
+    ) -> usize {
+        let owner = match self.get(pos) {
+            Some(player) => player,
+            None => return 0,
+        };
+
+        let mut length = 1;
+
+        for &(dc, dr) in [dir, (-dir.0, -dir.1)].iter() {
+            let mut cursor = pos;
+
+            while let Some(next) = self.step(cursor, (dc, dr)) {
+                if next == pos {
+                    return length;
+                }
+
+                if self.get(next) != Some(owner) {
+                    break;
+                }
+
+                length += 1;
+                cursor = next;
+            }
+        }
+
+        length
+    }
+
+    /// Check whether the piece at `pos` completes a winning line.
+    ///
+    /// Only lines through `pos` are inspected, so this is the incremental
+    /// counterpart to a full-board scan and should be called after each move.
+    pub(crate) fn wins_at(&self, pos: Position, rules: &Rules) -> bool {
+        match self.get(pos) {
+            Some(colour) => DIRECTIONS
+                .iter()
+                .any(|&dir| rules.is_win(self.run_length(pos, dir), colour)),
+            None => false,
+        }
+    }
+}
+
+/// m,n,k-game state.
+///
+/// `turn` and `winner` name seats, while the grid holds stone colours, with
+/// X as black. The two only differ once an opening protocol has `swapped`
+/// the seats' colours.
+#[derive(Debug)]
+pub(crate) struct MnkState {
+    pub(crate) grid: Grid,
+    pub(crate) rules: Rules,
+    pub(crate) phase: Phase,
+    pub(crate) swapped: bool,
+    pub(crate) turn: Player,
+    pub(crate) ongoing: bool,
+    pub(crate) winner: Option<Player>,
+    pub(crate) foul: Option<Foul>,
+    pub(crate) moves: usize,
+    /// Every step of the current game, for sharing it.
+    pub(crate) history: Vec<Action>,
+    pub(crate) log: Record,
+}
+
+impl MnkState {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Setup a new game under the given rules, with X going first.
+    pub(crate) fn new(rules: Rules) -> Self {
+        Self {
+            grid: rules.empty_grid(),
+            rules,
+            phase: rules.first_phase(),
+            swapped: false,
+            turn: Player::X,
+            ongoing: true,
+            winner: None,
+            foul: None,
+            moves: 0,
+            history: Vec::new(),
+            log: Record::new(),
+        }
+    }
+
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Stone colour played by the given seat.
+    pub(crate) fn colour(&self, seat: Player) -> Player {
+        if self.swapped {
+            seat.other()
+        } else {
+            seat
+        }
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Attempt to make a move on the current grid.
+    ///
+    /// Under gravity only the column of `pos` matters: the piece lands in
+    /// that column's lowest empty cell.
+    pub(crate) fn play(&mut self, pos: Position) -> MoveOutcome {
+        let pos = if self.rules.gravity {
+            match self.grid.landing(pos.0) {
+                Some(landing) => landing,
+                None => return MoveOutcome::NoChange,
+            }
+        } else {
+            pos
+        };
+
+        if !self.ongoing
+            || !self.grid.contains(pos)
+            || self.grid.get(pos).is_some()
+        {
+            return MoveOutcome::NoChange;
+        }
+
+        let colour = match &self.phase {
+            Phase::Placing(colours) => colours[0],
+            Phase::Choosing { .. } => return MoveOutcome::NoChange,
+            Phase::Main => self.colour(self.turn),
+        };
+
+        if self.rules.renju && colour == Player::X && self.phase == Phase::Main
+        {
+            if let Some(foul) = renju::foul_at(&self.grid, pos) {
+                self.foul = Some(foul);
+
+                return MoveOutcome::Forbidden(foul);
+            }
+        }
+
+        let (col, row) = pos;
+
+        self.grid.values[col][row] = Some(colour);
+        self.foul = None;
+        self.moves += 1;
+        self.history.push(Action::Place(pos));
+
+        if let Phase::Placing(colours) = &mut self.phase {
+            colours.remove(0);
+
+            if !colours.is_empty() {
+                return MoveOutcome::Continue;
+            }
+
+            let extend = self.rules.opening == Opening::Swap2
+                && self.moves == 3;
+
+            self.phase = Phase::Choosing { extend };
+            self.turn = self.turn.other();
+
+            return MoveOutcome::Switch;
+        }
+
+        if self.grid.wins_at(pos, &self.rules) {
+            let player = self.turn;
+
+            self.ongoing = false;
+            self.winner = Some(player);
+
+            self.log.add_win(player);
+
+            MoveOutcome::Win(player)
+        } else if self.moves == self.rules.width * self.rules.height {
+            self.ongoing = false;
+            self.log.draws += 1;
+
+            MoveOutcome::Draw
+        } else {
+            self.turn = self.turn.next(self.rules.players);
+
+            MoveOutcome::Switch
+        }
+    }
+
+    /// Settle the opening by choosing a colour, or place two more stones
+    /// under swap2.
+    ///
+    /// White always moves first once the colours are settled.
+    pub(crate) fn choose(&mut self, choice: Choice) -> MoveOutcome {
+        let extend = match self.phase {
+            Phase::Choosing { extend } if self.ongoing => extend,
+            _ => return MoveOutcome::NoChange,
+        };
+
+        let chooser = self.turn;
+
+        match choice {
+            Choice::Black => {
+                self.swapped = chooser != Player::X;
+                self.turn = chooser.other();
+                self.phase = Phase::Main;
+            }
+            Choice::White => {
+                self.swapped = chooser == Player::X;
+                self.phase = Phase::Main;
+            }
+            Choice::Extend if extend => {
+                self.phase = Phase::Placing(vec![Player::O, Player::X]);
+            }
+            Choice::Extend => return MoveOutcome::NoChange,
+        }
+
+        self.history.push(Action::Choose(choice));
+
+        MoveOutcome::Switch
+    }
+
+    /// Reset everthing besides the game log.
+    pub(crate) fn reset(&mut self) {
+        self.grid = self.rules.empty_grid();
+        self.phase = self.rules.first_phase();
+        self.swapped = false;
+        self.turn = Player::X;
+        self.ongoing = true;
+        self.winner = None;
+        self.foul = None;
+        self.moves = 0;
+        self.history.clear();
+    }
+
+    /// Switch to new rules, starting a fresh game.
+    pub(crate) fn configure(&mut self, rules: Rules) {
+        self.rules = rules;
+        self.reset();
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play_all(game: &mut MnkState, plays: &[Position]) -> MoveOutcome {
+        let (last, rest) = plays.split_last().unwrap();
+
+        for &pos in rest {
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+
+        game.play(*last)
+    }
+
+    #[test]
+    fn test_new_grid() {
+        let grid = Grid::new(7, 6);
+
+        assert_eq!(grid.values.len(), 7);
+        assert!(grid.values.iter().all(|column| column.len() == 6));
+        assert_eq!(grid.get((6, 5)), None);
+        assert!(grid.contains((6, 5)));
+        assert!(!grid.contains((7, 0)));
+        assert!(!grid.contains((0, 6)));
+    }
+
+    #[test]
+    fn test_run_length() {
+        let mut grid = Grid::new(15, 15);
+
+        for col in 3..7 {
+            grid.values[col][2] = Some(Player::X);
+        }
+        grid.values[7][2] = Some(Player::O);
+
+        assert_eq!(grid.run_length((5, 2), (1, 0)), 4);
+        assert_eq!(grid.run_length((3, 2), (1, 0)), 4);
+        assert_eq!(grid.run_length((5, 2), (0, 1)), 1);
+        assert_eq!(grid.run_length((7, 2), (1, 0)), 1);
+        assert_eq!(grid.run_length((8, 2), (1, 0)), 0);
+    }
+
+    #[test]
+    fn test_diagonal_five_wins() {
+        let mut game = MnkState::new(Rules::gomoku());
+        let plays = [
+            (4, 4), // X
+            (0, 0), // O
+            (5, 5),
+            (0, 1),
+            (7, 7),
+            (0, 2),
+            (8, 8),
+            (0, 3),
+            (6, 6),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::X));
+        assert_eq!(game.log.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_anti_diagonal_five_wins() {
+        let mut game = MnkState::new(Rules::gomoku());
+        let plays = [
+            (14, 14), // X
+            (4, 10),  // O
+            (13, 14),
+            (5, 9),
+            (12, 14),
+            (6, 8),
+            (0, 0),
+            (7, 7),
+            (1, 0),
+            (8, 6),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::O));
+    }
+
+    #[test]
+    fn test_overline() {
+        let plays = [
+            (0, 7), // X
+            (0, 0), // O
+            (1, 7),
+            (0, 1),
+            (2, 7),
+            (0, 2),
+            (4, 7),
+            (0, 4),
+            (5, 7),
+            (0, 9),
+            (3, 7),
+        ];
+
+        // Freestyle: the six-in-a-row counts
+        let mut freestyle = MnkState::new(Rules::gomoku());
+
+        assert_eq!(
+            play_all(&mut freestyle, &plays),
+            MoveOutcome::Win(Player::X)
+        );
+
+        // Standard: the six-in-a-row is ignored
+        let mut standard = MnkState::new(Rules {
+            exact: true,
+            ..Rules::gomoku()
+        });
+
+        assert_eq!(play_all(&mut standard, &plays), MoveOutcome::Switch);
+        assert_eq!(standard.play((0, 3)), MoveOutcome::Win(Player::O));
+    }
+
+    #[test]
+    fn test_configurable_size() {
+        let rules = Rules {
+            width: 3,
+            height: 3,
+            k: 3,
+            ..Rules::gomoku()
+        };
+        let mut game = MnkState::new(rules);
+        let plays = [
+            (0, 0), // X
+            (1, 0), // O
+            (2, 0),
+            (2, 1),
+            (0, 1),
+            (0, 2),
+            (1, 1),
+            (2, 2),
+            (1, 2),
+        ];
+
+        assert_eq!(game.play((3, 0)), MoveOutcome::NoChange);
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Draw);
+        assert_eq!(game.log.draws, 1);
+    }
+
+    #[test]
+    fn test_configure_resets() {
+        let mut game = MnkState::new(Rules::gomoku());
+
+        assert_eq!(game.play((14, 14)), MoveOutcome::Switch);
+
+        game.configure(Rules {
+            width: 19,
+            height: 19,
+            ..Rules::gomoku()
+        });
+
+        assert_eq!(game.turn, Player::X);
+        assert_eq!(game.grid.get((14, 14)), None);
+        assert_eq!(game.play((18, 18)), MoveOutcome::Switch);
+    }
+
+    #[test]
+    fn test_renju_forbids_black_double_three() {
+        let mut game = MnkState::new(Rules {
+            renju: true,
+            ..Rules::gomoku()
+        });
+        let plays = [
+            (3, 7), // X
+            (0, 0), // O
+            (4, 7),
+            (0, 14),
+            (5, 5),
+            (14, 0),
+            (5, 6),
+            (14, 14),
+        ];
+
+        for &pos in plays.iter() {
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+
+        assert_eq!(
+            game.play((5, 7)),
+            MoveOutcome::Forbidden(Foul::DoubleThree)
+        );
+        assert_eq!(game.foul, Some(Foul::DoubleThree));
+        assert_eq!(game.grid.get((5, 7)), None);
+        assert_eq!(game.turn, Player::X);
+
+        // Any other move is accepted and clears the foul
+        assert_eq!(game.play((9, 9)), MoveOutcome::Switch);
+        assert_eq!(game.foul, None);
+
+        // White is unrestricted
+        assert_eq!(game.play((5, 7)), MoveOutcome::Switch);
+    }
+
+    #[test]
+    fn test_renju_white_overline_wins() {
+        let mut game = MnkState::new(Rules {
+            renju: true,
+            ..Rules::gomoku()
+        });
+        let plays = [
+            (0, 0), // X
+            (2, 7), // O
+            (0, 2),
+            (3, 7),
+            (0, 4),
+            (4, 7),
+            (0, 6),
+            (6, 7),
+            (0, 8),
+            (7, 7),
+            (0, 10),
+            (5, 7),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::O));
+    }
+
+    #[test]
+    fn test_swap_opening() {
+        let mut game = MnkState::new(Rules {
+            opening: Opening::Swap,
+            ..Rules::gomoku()
+        });
+
+        // X's seat places black, white, black
+        assert_eq!(game.play((7, 7)), MoveOutcome::Continue);
+        assert_eq!(game.play((7, 8)), MoveOutcome::Continue);
+        assert_eq!(game.play((8, 7)), MoveOutcome::Switch);
+        assert_eq!(game.grid.get((7, 8)), Some(Player::O));
+        assert_eq!(game.turn, Player::O);
+
+        // O's seat must choose before playing on, and may not extend
+        assert_eq!(game.play((9, 9)), MoveOutcome::NoChange);
+        assert_eq!(game.choose(Choice::Extend), MoveOutcome::NoChange);
+        assert_eq!(game.choose(Choice::Black), MoveOutcome::Switch);
+
+        // O's seat now plays black, so X's seat moves with white
+        assert!(game.swapped);
+        assert_eq!(game.turn, Player::X);
+        assert_eq!(game.colour(Player::X), Player::O);
+        assert_eq!(game.play((9, 9)), MoveOutcome::Switch);
+        assert_eq!(game.grid.get((9, 9)), Some(Player::O));
+        assert_eq!(game.play((9, 7)), MoveOutcome::Switch);
+        assert_eq!(game.grid.get((9, 7)), Some(Player::X));
+    }
+
+    #[test]
+    fn test_swap2_extension_records_seat() {
+        let mut game = MnkState::new(Rules {
+            opening: Opening::Swap2,
+            ..Rules::gomoku()
+        });
+
+        assert_eq!(game.play((7, 7)), MoveOutcome::Continue);
+        assert_eq!(game.play((0, 0)), MoveOutcome::Continue);
+        assert_eq!(game.play((8, 7)), MoveOutcome::Switch);
+
+        // O's seat places white and black, then X's seat chooses
+        assert_eq!(game.choose(Choice::Extend), MoveOutcome::Switch);
+        assert_eq!(game.turn, Player::O);
+        assert_eq!(game.play((0, 1)), MoveOutcome::Continue);
+        assert_eq!(game.play((9, 7)), MoveOutcome::Switch);
+        assert_eq!(game.turn, Player::X);
+        assert_eq!(game.choose(Choice::Extend), MoveOutcome::NoChange);
+        assert_eq!(game.choose(Choice::Black), MoveOutcome::Switch);
+
+        // Black has three in a row; white moves first
+        assert!(!game.swapped);
+        assert_eq!(game.turn, Player::O);
+        assert_eq!(game.play((0, 2)), MoveOutcome::Switch);
+        assert_eq!(game.play((10, 7)), MoveOutcome::Switch);
+        assert_eq!(game.play((0, 3)), MoveOutcome::Switch);
+        assert_eq!(game.play((6, 7)), MoveOutcome::Win(Player::X));
+        assert_eq!(game.log.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_swapped_win_counts_for_seat() {
+        let mut game = MnkState::new(Rules {
+            opening: Opening::Swap,
+            ..Rules::gomoku()
+        });
+
+        assert_eq!(game.play((7, 7)), MoveOutcome::Continue);
+        assert_eq!(game.play((0, 0)), MoveOutcome::Continue);
+        assert_eq!(game.play((8, 7)), MoveOutcome::Switch);
+        assert_eq!(game.choose(Choice::Black), MoveOutcome::Switch);
+
+        // X's seat plays white, O's seat plays black
+        let plays = [
+            (0, 1), // X's seat
+            (9, 7), // O's seat
+            (0, 2),
+            (10, 7),
+            (14, 14),
+            (11, 7),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::O));
+        assert_eq!(game.winner, Some(Player::O));
+        assert_eq!(game.log.wins(Player::O), 1);
+    }
+
+    #[test]
+    fn test_landing() {
+        let mut grid = Grid::new(7, 6);
+
+        assert_eq!(grid.landing(3), Some((3, 5)));
+
+        grid.values[3][5] = Some(Player::X);
+        grid.values[3][4] = Some(Player::O);
+
+        assert_eq!(grid.landing(3), Some((3, 3)));
+        assert_eq!(grid.landing(7), None);
+
+        for row in 0..6 {
+            grid.values[0][row] = Some(Player::X);
+        }
+
+        assert_eq!(grid.landing(0), None);
+    }
+
+    #[test]
+    fn test_gravity_stacks_pieces() {
+        let mut game = MnkState::new(Rules::connect_four());
+
+        // Clicks anywhere in a column drop to its lowest empty cell
+        assert_eq!(game.play((2, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play((2, 3)), MoveOutcome::Switch);
+        assert_eq!(game.play((2, 5)), MoveOutcome::Switch);
+
+        assert_eq!(game.grid.get((2, 5)), Some(Player::X));
+        assert_eq!(game.grid.get((2, 4)), Some(Player::O));
+        assert_eq!(game.grid.get((2, 3)), Some(Player::X));
+        assert_eq!(game.grid.get((2, 0)), None);
+    }
+
+    #[test]
+    fn test_full_column() {
+        let mut game = MnkState::new(Rules::connect_four());
+
+        for _ in 0..6 {
+            assert_eq!(game.play((6, 0)), MoveOutcome::Switch);
+        }
+
+        assert_eq!(game.play((6, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((7, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.turn, Player::X);
+    }
+
+    #[test]
+    fn test_connect_four_vertical_win() {
+        let mut game = MnkState::new(Rules::connect_four());
+        let plays = [
+            (0, 0), // X
+            (1, 0), // O
+            (0, 0),
+            (1, 0),
+            (0, 0),
+            (1, 0),
+            (0, 0),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::X));
+    }
+
+    #[test]
+    fn test_connect_four_diagonal_win() {
+        let mut game = MnkState::new(Rules::connect_four());
+
+        //  .   .   .   O   .   .   .
+        //  .   .   O   X   .   .   .
+        //  .   O   X   X   .   .   O
+        //  O   X   X   X   .   .   O
+        let plays = [
+            (1, 0), // X
+            (0, 0), // O
+            (2, 0),
+            (1, 0),
+            (3, 0),
+            (6, 0),
+            (2, 0),
+            (6, 0),
+            (3, 0),
+            (2, 0),
+            (3, 0),
+            (3, 0),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::O));
+    }
+
+    #[test]
+    fn test_toroidal_diagonal_wins() {
+        let flat = Rules {
+            width: 3,
+            height: 3,
+            k: 3,
+            ..Rules::gomoku()
+        };
+        let plays = [
+            (2, 0), // X
+            (0, 0), // O
+            (0, 1),
+            (2, 2),
+            (1, 2),
+        ];
+
+        //  O   .   X
+        //  X   .   .
+        //  .   X   O
+
+        let mut game = MnkState::new(flat);
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Switch);
+
+        let mut game = MnkState::new(Rules { wrap: true, ..flat });
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::X));
+    }
+
+    #[test]
+    fn test_wrapped_run_counts_cells_once() {
+        let mut grid = Rules {
+            width: 4,
+            height: 4,
+            wrap: true,
+            ..Rules::gomoku()
+        }
+        .empty_grid();
+
+        for n in 0..4 {
+            grid.values[n][0] = Some(Player::X);
+            grid.values[n][n] = Some(Player::X);
+        }
+
+        assert_eq!(grid.run_length((2, 0), (1, 0)), 4);
+        assert_eq!(grid.run_length((2, 2), (1, 1)), 4);
+        assert_eq!(grid.step((3, 0), (1, -1)), Some((0, 3)));
+    }
+
+    #[test]
+    fn test_players_take_turns_in_order() {
+        let mut game = MnkState::new(Rules::multiplayer());
+        let turns = [Player::O, Player::Delta, Player::X, Player::O];
+
+        for (col, &next) in turns.iter().enumerate() {
+            assert_eq!(game.play((col, 0)), MoveOutcome::Switch);
+            assert_eq!(game.turn, next);
+        }
+
+        assert_eq!(game.grid.get((2, 0)), Some(Player::Delta));
+    }
+
+    #[test]
+    fn test_third_player_wins() {
+        let mut game = MnkState::new(Rules {
+            players: 4,
+            ..Rules::multiplayer()
+        });
+        let mut plays = Vec::new();
+
+        // Only Δ builds a line, down column 3
+        for n in 0..4 {
+            plays.push((n * 2, 5)); // X
+            plays.push((n * 2, 7)); // O
+            plays.push((3, n)); // Δ
+            plays.push((7, n * 2)); // Ψ
+        }
+        plays.truncate(15);
+
+        assert_eq!(
+            play_all(&mut game, &plays),
+            MoveOutcome::Win(Player::Delta)
+        );
+        assert_eq!(game.log.wins(Player::Delta), 1);
+        assert_eq!(game.log.wins(Player::X), 0);
+    }
+}
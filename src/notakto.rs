@@ -0,0 +1,191 @@
+//! Notakto: neutral tic-tac-toe over several boards, played misère.
+
+use super::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Notakto game state.
+///
+/// Both players place X marks. A board dies once it holds three in a row,
+/// and whoever kills the last live board loses.
+#[derive(Debug)]
+pub(crate) struct NotaktoState {
+    pub(crate) boards: Vec<Board>,
+    pub(crate) dead: Vec<bool>,
+    pub(crate) turn: Player,
+    pub(crate) ongoing: bool,
+    pub(crate) winner: Option<Player>,
+    pub(crate) log: Record,
+}
+
+impl NotaktoState {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Setup a new game over `count` boards, with X going first.
+    pub(crate) fn new(count: usize) -> Self {
+        Self {
+            boards: vec![Board::new(); count],
+            dead: vec![false; count],
+            turn: Player::X,
+            ongoing: true,
+            winner: None,
+            log: Record::new(),
+        }
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Attempt to place an X on cell `pos` of board `index`.
+    pub(crate) fn play(
+        &mut self,
+        index: usize,
+        (col, row): Position,
+    ) -> MoveOutcome {
+        if !self.ongoing
+            || index >= self.boards.len()
+            || self.dead[index]
+            || col >= 3
+            || row >= 3
+            || self.boards[index].values[col][row].is_some()
+        {
+            return MoveOutcome::NoChange;
+        }
+
+        let board = &mut self.boards[index];
+
+        board.values[col][row] = Some(Player::X);
+
+        if board.winner().is_some() {
+            self.dead[index] = true;
+        }
+
+        if self.dead.iter().all(|&dead| dead) {
+            let loser = self.turn;
+            let winner = loser.other();
+
+            self.ongoing = false;
+            self.winner = Some(winner);
+
+            self.log.add_win(winner);
+
+            MoveOutcome::Lose(loser)
+        } else {
+            self.turn = self.turn.other();
+
+            MoveOutcome::Switch
+        }
+    }
+
+    /// Reset everthing besides the game log, keeping the board count.
+    pub(crate) fn reset(&mut self) {
+        let count = self.boards.len();
+
+        self.boards = vec![Board::new(); count];
+        self.dead = vec![false; count];
+        self.turn = Player::X;
+        self.ongoing = true;
+        self.winner = None;
+    }
+
+    /// Switch to a different number of boards, starting a fresh game.
+    pub(crate) fn set_count(&mut self, count: usize) {
+        self.boards = vec![Board::new(); count];
+        self.reset();
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_board_loss() {
+        let mut game = NotaktoState::new(1);
+        let plays = [
+            (0, 0), // X
+            (1, 1), // O
+            (2, 2),
+        ];
+
+        //  X   .   .
+        //  .   X   .
+        //  .   .   X
+
+        assert_eq!(game.play(0, plays[0]), MoveOutcome::Switch);
+        assert_eq!(game.play(0, plays[1]), MoveOutcome::Switch);
+        assert_eq!(game.play(0, plays[2]), MoveOutcome::Lose(Player::X));
+        assert_eq!(game.winner, Some(Player::O));
+        assert_eq!(game.log.wins(Player::O), 1);
+        assert_eq!(game.play(0, (0, 1)), MoveOutcome::NoChange);
+    }
+
+    #[test]
+    fn test_dead_board_is_closed() {
+        let mut game = NotaktoState::new(2);
+
+        assert_eq!(game.play(0, (0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play(0, (1, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play(0, (2, 0)), MoveOutcome::Switch);
+        assert!(game.dead[0]);
+        assert!(!game.dead[1]);
+
+        // Only the live board accepts moves
+        assert_eq!(game.play(0, (1, 1)), MoveOutcome::NoChange);
+        assert_eq!(game.turn, Player::O);
+        assert_eq!(game.play(1, (1, 1)), MoveOutcome::Switch);
+    }
+
+    #[test]
+    fn test_last_board_loses() {
+        let mut game = NotaktoState::new(2);
+        let plays = [
+            (0, (0, 0)), // X
+            (0, (0, 1)), // O
+            (0, (0, 2)), // X kills board 0
+            (1, (1, 0)),
+            (1, (1, 1)),
+        ];
+
+        for &(index, pos) in plays.iter() {
+            assert_eq!(game.play(index, pos), MoveOutcome::Switch);
+        }
+
+        assert_eq!(game.play(1, (1, 2)), MoveOutcome::Lose(Player::O));
+        assert_eq!(game.winner, Some(Player::X));
+        assert_eq!(game.log.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_invalid_plays() {
+        let mut game = NotaktoState::new(1);
+
+        assert_eq!(game.play(1, (0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play(0, (3, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play(0, (0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play(0, (0, 0)), MoveOutcome::NoChange);
+    }
+
+    #[test]
+    fn test_set_count() {
+        let mut game = NotaktoState::new(1);
+
+        assert_eq!(game.play(0, (0, 0)), MoveOutcome::Switch);
+
+        game.set_count(3);
+
+        assert_eq!(game.boards.len(), 3);
+        assert_eq!(game.dead, vec![false; 3]);
+        assert_eq!(game.turn, Player::X);
+        assert_eq!(game.boards[0].values[0][0], None);
+    }
+}
@@ -0,0 +1,233 @@
+//! Numerical tic-tac-toe: players place the numbers 1 to 9, each once, and a
+//! full line summing to 15 wins.
+
+use super::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Sum of a winning line.
+const TARGET: u8 = 15;
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Numerical tic-tac-toe game state.
+///
+/// X places the odd numbers and moves first; O places the even ones.
+#[derive(Debug)]
+pub(crate) struct NumericalState {
+    pub(crate) board: Board<Option<u8>>,
+    /// Number the active player will place next.
+    pub(crate) number: u8,
+    pub(crate) turn: Player,
+    pub(crate) ongoing: bool,
+    pub(crate) winner: Option<Player>,
+    pub(crate) log: Record,
+}
+
+impl NumericalState {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Setup a new game, with X going first.
+    pub(crate) fn new() -> Self {
+        Self {
+            board: Board::new(),
+            number: 1,
+            turn: Player::X,
+            ongoing: true,
+            winner: None,
+            log: Record::new(),
+        }
+    }
+
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Player owning the given number.
+    pub(crate) fn owner(number: u8) -> Player {
+        if number % 2 == 1 {
+            Player::X
+        } else {
+            Player::O
+        }
+    }
+
+    /// Numbers the given player has yet to place, in increasing order.
+    pub(crate) fn remaining(&self, player: Player) -> Vec<u8> {
+        let placed: Vec<u8> = self
+            .board
+            .values
+            .iter()
+            .flatten()
+            .filter_map(|&n| n)
+            .collect();
+
+        (1..=9)
+            .filter(|&n| Self::owner(n) == player && !placed.contains(&n))
+            .collect()
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Attempt to place the selected number on the board.
+    pub(crate) fn play(&mut self, (col, row): Position) -> MoveOutcome {
+        if !self.ongoing
+            || col >= 3
+            || row >= 3
+            || self.board.values[col][row].is_some()
+        {
+            return MoveOutcome::NoChange;
+        }
+
+        self.board.values[col][row] = Some(self.number);
+
+        let line = self.board.find_line(|values| {
+            values.iter().all(|value| value.is_some())
+                && values.iter().filter_map(|&n| n).sum::<u8>() == TARGET
+        });
+
+        if line.is_some() {
+            let player = self.turn;
+
+            self.ongoing = false;
+            self.winner = Some(player);
+
+            self.log.add_win(player);
+
+            MoveOutcome::Win(player)
+        } else if self.board.is_full() {
+            self.ongoing = false;
+            self.log.draws += 1;
+
+            MoveOutcome::Draw
+        } else {
+            self.turn = self.turn.other();
+            self.number = self.remaining(self.turn)[0];
+
+            MoveOutcome::Switch
+        }
+    }
+
+    /// Choose the number to place next, if the active player still holds
+    /// it.
+    pub(crate) fn select_number(&mut self, number: u8) {
+        if self.remaining(self.turn).contains(&number) {
+            self.number = number;
+        }
+    }
+
+    /// Reset everthing besides the game log.
+    pub(crate) fn reset(&mut self) {
+        self.board = Board::new();
+        self.number = 1;
+        self.turn = Player::X;
+        self.ongoing = true;
+        self.winner = None;
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play_number(
+        game: &mut NumericalState,
+        number: u8,
+        pos: Position,
+    ) -> MoveOutcome {
+        game.select_number(number);
+        assert_eq!(game.number, number);
+        game.play(pos)
+    }
+
+    #[test]
+    fn test_line_summing_to_fifteen_wins() {
+        let mut game = NumericalState::new();
+        let plays = [
+            (9, (0, 0)), // X
+            (2, (1, 0)), // O
+            (1, (2, 2)),
+        ];
+
+        for &(number, pos) in plays.iter() {
+            assert_eq!(
+                play_number(&mut game, number, pos),
+                MoveOutcome::Switch
+            );
+        }
+
+        // Lines mix both players' numbers: 9 + 2 + 4 along the top row
+        assert_eq!(
+            play_number(&mut game, 4, (2, 0)),
+            MoveOutcome::Win(Player::O)
+        );
+        assert_eq!(game.log.wins(Player::O), 1);
+    }
+
+    #[test]
+    fn test_partial_line_does_not_win() {
+        let mut game = NumericalState::new();
+
+        // 7 + 8 is fifteen, but the line is not full
+        assert_eq!(play_number(&mut game, 7, (0, 0)), MoveOutcome::Switch);
+        assert_eq!(play_number(&mut game, 8, (1, 0)), MoveOutcome::Switch);
+        assert!(game.ongoing);
+    }
+
+    #[test]
+    fn test_numbers_are_used_once() {
+        let mut game = NumericalState::new();
+
+        assert_eq!(game.remaining(Player::X), vec![1, 3, 5, 7, 9]);
+        assert_eq!(game.remaining(Player::O), vec![2, 4, 6, 8]);
+        assert_eq!(play_number(&mut game, 3, (1, 1)), MoveOutcome::Switch);
+        assert_eq!(game.remaining(Player::X), vec![1, 5, 7, 9]);
+
+        // O cannot pick an odd number, nor X's used one
+        assert_eq!(game.number, 2);
+        game.select_number(3);
+        game.select_number(5);
+        assert_eq!(game.number, 2);
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut game = NumericalState::new();
+        let plays = [
+            (1, (0, 0)), // X
+            (2, (1, 0)), // O
+            (3, (2, 0)),
+            (4, (0, 1)),
+            (5, (1, 1)),
+            (6, (0, 2)),
+            (7, (2, 1)),
+            (8, (2, 2)),
+        ];
+
+        //  1   2   3
+        //  4   5   7
+        //  6   9   8
+
+        for &(number, pos) in plays.iter() {
+            assert_eq!(
+                play_number(&mut game, number, pos),
+                MoveOutcome::Switch
+            );
+        }
+
+        assert_eq!(play_number(&mut game, 9, (1, 2)), MoveOutcome::Draw);
+        assert_eq!(game.log.draws, 1);
+    }
+}
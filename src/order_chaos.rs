@@ -0,0 +1,258 @@
+//! Order and Chaos: either side may place either symbol on a 6x6 board.
+//!
+//! Order wins with five of one symbol in a row, while Chaos wins by filling
+//! the board without one.
+
+use super::*;
+
+use mnk::{Grid, DIRECTIONS};
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Width and height of the board.
+pub(crate) const SIZE: usize = 6;
+
+/// Symbols in a row that win for Order.
+const LINE: usize = 5;
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// The two sides, which unlike `Player` do not own a symbol.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Role {
+    Order,
+    Chaos,
+}
+
+impl Role {
+    /// The opposing role.
+    pub(crate) fn other(self) -> Self {
+        match self {
+            Role::Order => Role::Chaos,
+            Role::Chaos => Role::Order,
+        }
+    }
+
+    /// Catalog key of the role's name.
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            Role::Order => "role.order",
+            Role::Chaos => "role.chaos",
+        }
+    }
+
+    /// Seat playing the role, with Order in the first seat.
+    pub(crate) fn seat(self) -> Player {
+        match self {
+            Role::Order => Player::X,
+            Role::Chaos => Player::O,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Order and Chaos game state.
+///
+/// The game log counts each role's wins under the seat playing it; the game
+/// cannot be drawn.
+#[derive(Debug)]
+pub(crate) struct OrderChaosState {
+    pub(crate) grid: Grid,
+    /// Symbol the active role will place next.
+    pub(crate) symbol: Player,
+    pub(crate) turn: Role,
+    pub(crate) ongoing: bool,
+    pub(crate) winner: Option<Role>,
+    pub(crate) moves: usize,
+    pub(crate) log: Record,
+}
+
+impl OrderChaosState {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Setup a new game, with Order going first.
+    pub(crate) fn new() -> Self {
+        Self {
+            grid: Grid::new(SIZE, SIZE),
+            symbol: Player::X,
+            turn: Role::Order,
+            ongoing: true,
+            winner: None,
+            moves: 0,
+            log: Record::new(),
+        }
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Attempt to place the selected symbol on the board.
+    pub(crate) fn play(&mut self, pos: Position) -> MoveOutcome {
+        if !self.ongoing
+            || !self.grid.contains(pos)
+            || self.grid.get(pos).is_some()
+        {
+            return MoveOutcome::NoChange;
+        }
+
+        let (col, row) = pos;
+
+        self.grid.values[col][row] = Some(self.symbol);
+        self.moves += 1;
+
+        let five = DIRECTIONS
+            .iter()
+            .any(|&dir| self.grid.run_length(pos, dir) >= LINE);
+
+        let winner = if five {
+            Role::Order
+        } else if self.moves == SIZE * SIZE {
+            Role::Chaos
+        } else {
+            self.turn = self.turn.other();
+
+            return MoveOutcome::Switch;
+        };
+
+        self.ongoing = false;
+        self.winner = Some(winner);
+
+        self.log.add_win(winner.seat());
+
+        MoveOutcome::Win(winner.seat())
+    }
+
+    /// Choose the symbol to place next.
+    pub(crate) fn select_symbol(&mut self, symbol: Player) {
+        self.symbol = symbol;
+    }
+
+    /// Reset everthing besides the game log.
+    pub(crate) fn reset(&mut self) {
+        self.grid = Grid::new(SIZE, SIZE);
+        self.symbol = Player::X;
+        self.turn = Role::Order;
+        self.ongoing = true;
+        self.winner = None;
+        self.moves = 0;
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play_as(
+        game: &mut OrderChaosState,
+        symbol: Player,
+        pos: Position,
+    ) -> MoveOutcome {
+        game.select_symbol(symbol);
+        game.play(pos)
+    }
+
+    #[test]
+    fn test_either_role_places_either_symbol() {
+        let mut game = OrderChaosState::new();
+
+        assert_eq!(play_as(&mut game, Player::O, (0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.turn, Role::Chaos);
+        assert_eq!(play_as(&mut game, Player::O, (1, 0)), MoveOutcome::Switch);
+        assert_eq!(game.turn, Role::Order);
+        assert_eq!(game.grid.get((0, 0)), Some(Player::O));
+        assert_eq!(game.grid.get((1, 0)), Some(Player::O));
+    }
+
+    #[test]
+    fn test_order_wins_with_five() {
+        let mut game = OrderChaosState::new();
+
+        // Chaos unwittingly helps build a diagonal of Os
+        for n in 0..4 {
+            assert_eq!(
+                play_as(&mut game, Player::O, (n + 1, n + 1)),
+                MoveOutcome::Switch
+            );
+        }
+
+        assert_eq!(
+            play_as(&mut game, Player::O, (5, 5)),
+            MoveOutcome::Win(Player::X)
+        );
+        assert_eq!(game.winner, Some(Role::Order));
+        assert_eq!(game.log.wins(Role::Order.seat()), 1);
+    }
+
+    #[test]
+    fn test_mixed_line_does_not_win() {
+        let mut game = OrderChaosState::new();
+        let plays = [
+            (Player::X, (0, 2)),
+            (Player::X, (1, 2)),
+            (Player::O, (2, 2)),
+            (Player::X, (3, 2)),
+            (Player::X, (4, 2)),
+        ];
+
+        for &(symbol, pos) in plays.iter() {
+            assert_eq!(play_as(&mut game, symbol, pos), MoveOutcome::Switch);
+        }
+
+        assert!(game.ongoing);
+    }
+
+    #[test]
+    fn test_chaos_wins_on_full_board() {
+        let mut game = OrderChaosState::new();
+
+        // Pairs of columns alternate symbols, and every row flips the
+        // pattern, so no line runs longer than two
+        let symbol = |(col, row): Position| {
+            if (col / 2 + row) % 2 == 0 {
+                Player::X
+            } else {
+                Player::O
+            }
+        };
+
+        for col in 0..SIZE {
+            for row in 0..SIZE {
+                if (col, row) != (5, 5) {
+                    game.grid.values[col][row] = Some(symbol((col, row)));
+                }
+            }
+        }
+        game.moves = SIZE * SIZE - 1;
+
+        assert_eq!(
+            play_as(&mut game, symbol((5, 5)), (5, 5)),
+            MoveOutcome::Win(Player::O)
+        );
+        assert_eq!(game.winner, Some(Role::Chaos));
+        assert_eq!(game.log.wins(Role::Chaos.seat()), 1);
+    }
+
+    #[test]
+    fn test_invalid_plays() {
+        let mut game = OrderChaosState::new();
+
+        assert_eq!(game.play((SIZE, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play((0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.turn, Role::Chaos);
+    }
+}
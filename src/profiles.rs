@@ -0,0 +1,522 @@
+//! Named player profiles, computer strategies and the history of games
+//! they played.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Local storage key the profiles and their history are saved under.
+pub(crate) const STORAGE_KEY: &str = "tic-tac-yew.profiles";
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// Anyone who can take a seat and be rated.
+///
+/// Both are saved as plain strings, strategies under their reserved ids, so
+/// that histories from before strategies could be seated still load.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+pub(crate) enum Entity {
+    Strategy(Strategy),
+    Profile(String),
+}
+
+/// One completed game between seated profiles and strategies.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct Game {
+    pub(crate) mode: Mode,
+    /// Entity in each seat, indexed by turn order.
+    pub(crate) players: Vec<Entity>,
+    /// Seat that moved first.
+    pub(crate) first: Player,
+    pub(crate) winner: Option<Player>,
+    /// Rating of each player after the game, indexed by turn order.
+    #[serde(default)]
+    pub(crate) ratings: Vec<i32>,
+}
+
+impl Game {
+    /// Seat the given entity played in, if they took part.
+    fn seat(&self, entity: &Entity) -> Option<Player> {
+        self.players
+            .iter()
+            .position(|player| player == entity)
+            .map(|index| Player::ALL[index])
+    }
+
+    /// Result of the game for the given seat.
+    fn result(&self, seat: Player) -> Outcome {
+        match self.winner {
+            Some(winner) if winner == seat => Outcome::Win,
+            Some(_) => Outcome::Loss,
+            None => Outcome::Draw,
+        }
+    }
+}
+
+/// Result of a game from one player's point of view.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl Outcome {
+    /// Catalog key counting streaks of the outcome.
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            Outcome::Win => "streak.win",
+            Outcome::Loss => "streak.loss",
+            Outcome::Draw => "streak.draw",
+        }
+    }
+}
+
+/// Wins, losses and draws over some set of games.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Tally {
+    pub(crate) wins: u32,
+    pub(crate) losses: u32,
+    pub(crate) draws: u32,
+}
+
+impl Tally {
+    /// Count one more game with the given result.
+    fn add(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Loss => self.losses += 1,
+            Outcome::Draw => self.draws += 1,
+        }
+    }
+
+    /// Games counted.
+    pub(crate) fn played(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    /// Percentage of games won, if any were played.
+    pub(crate) fn win_rate(&self) -> Option<u32> {
+        match self.played() {
+            0 => None,
+            played => Some(self.wins * 100 / played),
+        }
+    }
+}
+
+/// Statistics of one entity over its whole history.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Totals {
+    pub(crate) overall: Tally,
+    /// Games in which the entity moved first.
+    pub(crate) first: Tally,
+    /// Games in which another player moved first.
+    pub(crate) second: Tally,
+    /// Result of the latest games, and how many in a row ended that way.
+    pub(crate) streak: Option<(Outcome, u32)>,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Every profile, who is sitting in which seat, and the games they played.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct Profiles {
+    pub(crate) names: Vec<String>,
+    /// Entity in each seat, indexed by turn order, or `None` for a guest.
+    pub(crate) seats: Vec<Option<Entity>>,
+    /// Completed games, oldest first.
+    pub(crate) history: Vec<Game>,
+    /// Current rating of every entity that has played, rebuilt from the
+    /// history when restored.
+    #[serde(skip)]
+    ratings: HashMap<Entity, i32>,
+}
+
+impl Default for Profiles {
+    fn default() -> Self {
+        Self {
+            names: Vec::new(),
+            seats: vec![None; Player::ALL.len()],
+            history: Vec::new(),
+            ratings: HashMap::new(),
+        }
+    }
+}
+
+impl Profiles {
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Entity sitting in the given seat, if any.
+    pub(crate) fn seated(&self, seat: Player) -> Option<&Entity> {
+        self.seats[seat.index()].as_ref()
+    }
+
+    /// Every rated entity: each profile, then each strategy that has
+    /// played.
+    pub(crate) fn entities(&self) -> Vec<Entity> {
+        let profiles = self.names.iter().cloned().map(Entity::Profile);
+        let strategies = Strategy::ALL
+            .iter()
+            .map(|&strategy| Entity::Strategy(strategy))
+            .filter(|strategy| self.ratings.contains_key(strategy));
+
+        profiles.chain(strategies).collect()
+    }
+
+    /// Check whether restored profiles cover every seat.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.seats.len() == Player::ALL.len()
+    }
+
+    /// Rating of the given entity after each of their games, oldest first.
+    pub(crate) fn rating_history(&self, entity: &Entity) -> Vec<i32> {
+        self.history
+            .iter()
+            .filter_map(|game| {
+                let seat = game.seat(entity)?;
+                game.ratings.get(seat.index()).cloned()
+            })
+            .collect()
+    }
+
+    /// Current rating of the given entity.
+    pub(crate) fn rating(&self, entity: &Entity) -> i32 {
+        self.ratings.get(entity).cloned().unwrap_or(rating::INITIAL)
+    }
+
+    /// Statistics of the given entity.
+    pub(crate) fn totals(&self, entity: &Entity) -> Totals {
+        let mut totals = Totals::default();
+
+        for game in self.history.iter() {
+            let seat = match game.seat(entity) {
+                Some(seat) => seat,
+                None => continue,
+            };
+            let outcome = game.result(seat);
+
+            totals.overall.add(outcome);
+
+            if seat == game.first {
+                totals.first.add(outcome);
+            } else {
+                totals.second.add(outcome);
+            }
+
+            totals.streak = match totals.streak {
+                Some((last, count)) if last == outcome => {
+                    Some((outcome, count + 1))
+                }
+                _ => Some((outcome, 1)),
+            };
+        }
+
+        totals
+    }
+
+    /// Results of `entity` against `rival` in the games both took part in.
+    ///
+    /// Games won by a third player count for neither.
+    pub(crate) fn head_to_head(
+        &self,
+        entity: &Entity,
+        rival: &Entity,
+    ) -> Tally {
+        let mut tally = Tally::default();
+
+        for game in self.history.iter() {
+            if let (Some(seat), Some(rival_seat)) =
+                (game.seat(entity), game.seat(rival))
+            {
+                match game.winner {
+                    Some(winner) if winner == seat => tally.add(Outcome::Win),
+                    Some(winner) if winner == rival_seat => {
+                        tally.add(Outcome::Loss)
+                    }
+                    Some(_) => {}
+                    None => tally.add(Outcome::Draw),
+                }
+            }
+        }
+
+        tally
+    }
+
+    //////////////////////////////////
+    // Modification
+    //////////////////////////////////
+
+    /// Rebuild the current ratings from the history, which is all that is
+    /// saved.
+    pub(crate) fn restore_ratings(&mut self) {
+        self.ratings.clear();
+
+        for game in self.history.iter() {
+            for (entity, &rating) in game.players.iter().zip(&game.ratings) {
+                self.ratings.insert(entity.clone(), rating);
+            }
+        }
+    }
+
+    /// Create a new profile, unless the name is blank, already taken or
+    /// reserved for a strategy.
+    pub(crate) fn add(&mut self, name: &str) -> bool {
+        let name: String =
+            name.trim().chars().take(settings::MAX_NAME).collect();
+        let reserved = Strategy::ALL.iter().any(|s| s.id() == name);
+
+        if name.is_empty() || reserved || self.names.contains(&name) {
+            return false;
+        }
+
+        self.names.push(name);
+
+        true
+    }
+
+    /// Seat a profile, a strategy or a guest, moving a profile out of any
+    /// other seat. A strategy may take several seats.
+    pub(crate) fn seat(&mut self, seat: Player, entity: Option<Entity>) {
+        if let Some(Entity::Profile(_)) = entity {
+            for seated in self.seats.iter_mut() {
+                if *seated == entity {
+                    *seated = None;
+                }
+            }
+        }
+
+        self.seats[seat.index()] = entity;
+    }
+
+    /// Strategy playing for the given seat, if any.
+    pub(crate) fn strategy(&self, seat: Player) -> Option<Strategy> {
+        match self.seated(seat) {
+            Some(&Entity::Strategy(strategy)) => Some(strategy),
+            _ => None,
+        }
+    }
+
+    /// Add a finished game to the history, if every seat in play was taken.
+    /// Strategies only play the classic board, so they are never credited
+    /// with other games, and a strategy playing itself proves nothing about
+    /// its strength. Returns whether the game was recorded.
+    pub(crate) fn record(
+        &mut self,
+        mode: Mode,
+        count: usize,
+        first: Player,
+        winner: Option<Player>,
+    ) -> bool {
+        let players: Option<Vec<Entity>> =
+            self.seats[..count].iter().cloned().collect();
+        let strategies =
+            (0..count).any(|i| self.strategy(Player::ALL[i]).is_some());
+
+        if strategies && mode != Mode::Classic {
+            return false;
+        }
+
+        match players {
+            Some(players) if is_self_play(&players) => false,
+            Some(players) => {
+                let before: Vec<i32> =
+                    players.iter().map(|entity| self.rating(entity)).collect();
+                let ratings =
+                    rating::update(&before, winner.map(Player::index));
+
+                for (entity, &rating) in players.iter().zip(&ratings) {
+                    self.ratings.insert(entity.clone(), rating);
+                }
+
+                self.history.push(Game {
+                    mode,
+                    players,
+                    first,
+                    winner,
+                    ratings,
+                });
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Helpers
+//////////////////////////////////////////////////////////////////////////////
+
+/// Check whether one entity holds every seat, which only a strategy can.
+fn is_self_play(players: &[Entity]) -> bool {
+    players.iter().all(|player| *player == players[0])
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(name: &str) -> Entity {
+        Entity::Profile(name.to_string())
+    }
+
+    fn seated(names: &[&str]) -> Profiles {
+        let mut profiles = Profiles::default();
+
+        for (&name, &seat) in names.iter().zip(Player::ALL.iter()) {
+            assert!(profiles.add(name));
+            profiles.seat(seat, Some(profile(name)));
+        }
+
+        profiles
+    }
+
+    #[test]
+    fn test_add_and_seat() {
+        let mut profiles = seated(&["Ann", "Bob"]);
+
+        assert!(!profiles.add(" Ann "));
+        assert!(!profiles.add("  "));
+        assert!(!profiles.add("ai:minimax"));
+
+        // Moving Ann into the second seat leaves the first one to a guest
+        profiles.seat(Player::O, Some(profile("Ann")));
+        assert_eq!(profiles.seated(Player::X), None);
+        assert_eq!(profiles.seated(Player::O), Some(&profile("Ann")));
+
+        // A strategy can play itself
+        let strategy = Entity::Strategy(Strategy::Random);
+        profiles.seat(Player::X, Some(strategy.clone()));
+        profiles.seat(Player::O, Some(strategy.clone()));
+        assert_eq!(profiles.seated(Player::X), Some(&strategy));
+    }
+
+    #[test]
+    fn test_self_play_is_not_recorded() {
+        let mut profiles = Profiles::default();
+        let strategy = Entity::Strategy(Strategy::Random);
+
+        profiles.seat(Player::X, Some(strategy.clone()));
+        profiles.seat(Player::O, Some(strategy.clone()));
+
+        let recorded =
+            profiles.record(Mode::Classic, 2, Player::X, Some(Player::X));
+
+        assert!(!recorded);
+        assert!(profiles.history.is_empty());
+        assert_eq!(profiles.rating(&strategy), rating::INITIAL);
+        assert_eq!(profiles.totals(&strategy).overall.played(), 0);
+    }
+
+    #[test]
+    fn test_guests_are_not_recorded() {
+        let mut profiles = seated(&["Ann"]);
+
+        assert!(!profiles.record(Mode::Classic, 2, Player::X, None));
+        assert!(profiles.history.is_empty());
+    }
+
+    #[test]
+    fn test_totals() {
+        let mut profiles = seated(&["Ann", "Bob"]);
+
+        profiles.record(Mode::Classic, 2, Player::X, Some(Player::X));
+        profiles.record(Mode::Classic, 2, Player::O, None);
+        profiles.record(Mode::Classic, 2, Player::X, Some(Player::O));
+        profiles.record(Mode::Classic, 2, Player::O, Some(Player::O));
+
+        let ann = profiles.totals(&profile("Ann"));
+
+        assert_eq!(ann.overall.played(), 4);
+        assert_eq!(ann.overall.wins, 1);
+        assert_eq!(ann.first.wins, 1);
+        assert_eq!(ann.first.played(), 2);
+        assert_eq!(ann.second.draws, 1);
+        assert_eq!(ann.streak, Some((Outcome::Loss, 2)));
+        let bob = profiles.totals(&profile("Bob"));
+        assert_eq!(bob.overall.win_rate(), Some(50));
+    }
+
+    #[test]
+    fn test_head_to_head() {
+        let mut profiles = seated(&["Ann", "Bob", "Cy"]);
+
+        profiles.record(Mode::Multiplayer, 3, Player::X, Some(Player::X));
+        profiles.record(Mode::Multiplayer, 3, Player::X, Some(Player::Delta));
+        profiles.record(Mode::Classic, 2, Player::X, None);
+
+        let (ann, bob, cy) = (profile("Ann"), profile("Bob"), profile("Cy"));
+        let tally = profiles.head_to_head(&ann, &bob);
+
+        assert_eq!(tally.wins, 1);
+        assert_eq!(tally.losses, 0);
+        assert_eq!(tally.draws, 1);
+        assert_eq!(profiles.head_to_head(&cy, &bob).wins, 1);
+    }
+
+    #[test]
+    fn test_ratings() {
+        let mut profiles = seated(&["Ann", "Bob"]);
+
+        assert_eq!(profiles.rating(&profile("Ann")), rating::INITIAL);
+
+        profiles.record(Mode::Classic, 2, Player::X, Some(Player::X));
+        profiles.record(Mode::Classic, 2, Player::O, None);
+
+        let history = profiles.rating_history(&profile("Ann"));
+
+        assert_eq!(history, vec![1516, 1515]);
+        assert_eq!(profiles.rating(&profile("Bob")), 1485);
+
+        // Only the history is saved, so ratings are rebuilt from it
+        let mut restored = Profiles {
+            ratings: HashMap::new(),
+            ..profiles.clone()
+        };
+
+        restored.restore_ratings();
+        assert_eq!(restored, profiles);
+    }
+
+    #[test]
+    fn test_strategies_are_rated() {
+        let mut profiles = seated(&["Ann"]);
+        let strategy = Entity::Strategy(Strategy::Minimax);
+
+        profiles.seat(Player::O, Some(strategy.clone()));
+
+        // Strategies only play the classic board
+        assert!(!profiles.record(Mode::Gomoku, 2, Player::X, None));
+        assert!(profiles.record(Mode::Classic, 2, Player::X, Some(Player::O)));
+
+        assert_eq!(profiles.rating(&strategy), 1516);
+        assert_eq!(profiles.entities(), vec![profile("Ann"), strategy]);
+    }
+
+    #[test]
+    fn test_entities_are_saved_as_names() {
+        let players = vec![profile("Ann"), Entity::Strategy(Strategy::Random)];
+        let json = serde_json::to_string(&players).unwrap();
+
+        assert_eq!(json, r#"["Ann","ai:random"]"#);
+
+        // Histories saved before strategies could be seated still load
+        let restored: Vec<Entity> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, players);
+    }
+}
@@ -0,0 +1,544 @@
+//! Quantum tic-tac-toe, after Allan Goff.
+//!
+//! Each move places a pair of entangled "spooky" marks in two cells. When a
+//! move closes a cycle of entanglements, the opponent of the player who made
+//! it chooses where that move's mark collapses, and every mark entangled with
+//! it collapses in turn.
+
+use super::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// A mark, labelled with the number of the move that placed it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Mark {
+    pub(crate) player: Player,
+    pub(crate) number: usize,
+}
+
+/// A quantum board cell: either collapsed to one classical mark, or holding
+/// any number of spooky marks.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Cell {
+    pub(crate) classical: Option<Mark>,
+    pub(crate) spooky: Vec<Mark>,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// Quantum tic-tac-toe board.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct QuantumBoard {
+    pub(crate) cells: [[Cell; 3]; 3],
+    /// Cells joined by each move, indexed by move number minus one.
+    pub(crate) moves: Vec<(Position, Position)>,
+}
+
+impl QuantumBoard {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create an empty quantum board.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Cell at the given position.
+    pub(crate) fn cell(&self, (col, row): Position) -> &Cell {
+        &self.cells[col][row]
+    }
+
+    /// Cells not yet collapsed to a classical mark.
+    pub(crate) fn open_cells(&self) -> Vec<Position> {
+        POSITIONS
+            .iter()
+            .cloned()
+            .filter(|&pos| self.cell(pos).classical.is_none())
+            .collect()
+    }
+
+    //////////////////////////////////
+    // Evaluation
+    //////////////////////////////////
+
+    /// Check whether two cells are already joined by a chain of spooky
+    /// marks, so that entangling them would close a cycle.
+    pub(crate) fn connected(&self, from: Position, to: Position) -> bool {
+        let mut seen = vec![from];
+        let mut frontier = vec![from];
+
+        while let Some(pos) = frontier.pop() {
+            if pos == to {
+                return true;
+            }
+
+            for mark in self.cell(pos).spooky.iter() {
+                let (a, b) = self.moves[mark.number - 1];
+                let next = if a == pos { b } else { a };
+
+                if !seen.contains(&next) {
+                    seen.push(next);
+                    frontier.push(next);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Completed lines of classical marks, each with its owner and the
+    /// highest move number among its marks.
+    pub(crate) fn lines(&self) -> Vec<(Player, usize)> {
+        board::LINES
+            .iter()
+            .filter_map(|line| {
+                let marks: Vec<Mark> = line
+                    .iter()
+                    .filter_map(|&pos| self.cell(pos).classical)
+                    .collect();
+                let player = marks.first()?.player;
+
+                if marks.len() == 3 && marks.iter().all(|m| m.player == player)
+                {
+                    let newest = marks.iter().map(|m| m.number).max()?;
+                    Some((player, newest))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    //////////////////////////////////
+    // Modification
+    //////////////////////////////////
+
+    /// Entangle two cells with a new spooky mark for `player`, returning
+    /// the mark.
+    pub(crate) fn entangle(
+        &mut self,
+        player: Player,
+        (a, b): (Position, Position),
+    ) -> Mark {
+        let mark = Mark {
+            player,
+            number: self.moves.len() + 1,
+        };
+
+        self.moves.push((a, b));
+        self.cells[a.0][a.1].spooky.push(mark);
+        self.cells[b.0][b.1].spooky.push(mark);
+
+        mark
+    }
+
+    /// Place a classical mark directly, as the last move of a game does.
+    pub(crate) fn place(&mut self, player: Player, pos: Position) {
+        let mark = Mark {
+            player,
+            number: self.moves.len() + 1,
+        };
+
+        self.moves.push((pos, pos));
+        self.cells[pos.0][pos.1].classical = Some(mark);
+    }
+
+    /// Collapse `mark` into `pos`, forcing every other spooky mark there
+    /// into its partner cell, and so on down the chain.
+    pub(crate) fn collapse(&mut self, mark: Mark, pos: Position) {
+        let mut pending = vec![(mark, pos)];
+
+        while let Some((mark, pos)) = pending.pop() {
+            if self.cell(pos).classical.is_some() {
+                continue;
+            }
+
+            let (a, b) = self.moves[mark.number - 1];
+
+            for &end in [a, b].iter() {
+                self.cells[end.0][end.1].spooky.retain(|m| *m != mark);
+            }
+
+            let cell = &mut self.cells[pos.0][pos.1];
+            let displaced: Vec<Mark> = cell.spooky.drain(..).collect();
+
+            cell.classical = Some(mark);
+
+            for other in displaced {
+                let (a, b) = self.moves[other.number - 1];
+                let partner = if a == pos { b } else { a };
+
+                pending.push((other, partner));
+            }
+        }
+    }
+}
+
+/// Quantum tic-tac-toe game state.
+///
+/// Scores are kept in half-points: when a collapse completes lines for both
+/// players, the one whose line has the lower maximum move number scores a
+/// full point and the other half a point.
+#[derive(Debug)]
+pub(crate) struct QuantumState {
+    pub(crate) board: QuantumBoard,
+    /// First cell of a spooky move still being made.
+    pub(crate) pending: Option<Position>,
+    /// Mark that closed a cycle, awaiting the active player's collapse.
+    pub(crate) cycle: Option<Mark>,
+    pub(crate) turn: Player,
+    pub(crate) ongoing: bool,
+    pub(crate) winner: Option<Player>,
+    pub(crate) xscore: u32,
+    pub(crate) oscore: u32,
+    pub(crate) log: Record,
+}
+
+impl QuantumState {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Setup a new game, with X going first.
+    pub(crate) fn new() -> Self {
+        Self {
+            board: QuantumBoard::new(),
+            pending: None,
+            cycle: None,
+            turn: Player::X,
+            ongoing: true,
+            winner: None,
+            xscore: 0,
+            oscore: 0,
+            log: Record::new(),
+        }
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Attempt to mark a cell.
+    ///
+    /// A spooky move takes two calls, one per cell; the first returns
+    /// `Continue`. When only one cell is left open, a single call places a
+    /// classical mark there.
+    pub(crate) fn play(&mut self, pos: Position) -> MoveOutcome {
+        let (col, row) = pos;
+
+        if !self.ongoing
+            || self.cycle.is_some()
+            || col >= 3
+            || row >= 3
+            || self.board.cell(pos).classical.is_some()
+        {
+            return MoveOutcome::NoChange;
+        }
+
+        if self.board.open_cells().len() == 1 {
+            self.board.place(self.turn, pos);
+
+            return self.settle();
+        }
+
+        match self.pending {
+            None => {
+                self.pending = Some(pos);
+
+                MoveOutcome::Continue
+            }
+            Some(first) if first == pos => MoveOutcome::NoChange,
+            Some(first) => {
+                let closes_cycle = self.board.connected(first, pos);
+                let mark = self.board.entangle(self.turn, (first, pos));
+
+                self.pending = None;
+                self.turn = self.turn.other();
+
+                if closes_cycle {
+                    self.cycle = Some(mark);
+                }
+
+                MoveOutcome::Switch
+            }
+        }
+    }
+
+    /// Resolve a pending cycle by collapsing its closing mark into `pos`,
+    /// one of the two cells that mark occupies.
+    pub(crate) fn collapse(&mut self, pos: Position) -> MoveOutcome {
+        let mark = match self.cycle {
+            Some(mark) if self.ongoing => mark,
+            _ => return MoveOutcome::NoChange,
+        };
+        let (a, b) = self.board.moves[mark.number - 1];
+
+        if pos != a && pos != b {
+            return MoveOutcome::NoChange;
+        }
+
+        self.board.collapse(mark, pos);
+        self.cycle = None;
+
+        // The chooser moves next, so the turn stays with them
+        match self.settle() {
+            MoveOutcome::Switch => {
+                self.turn = self.turn.other();
+                MoveOutcome::Continue
+            }
+            outcome => outcome,
+        }
+    }
+
+    /// Reset everthing besides the game log.
+    pub(crate) fn reset(&mut self) {
+        self.board = QuantumBoard::new();
+        self.pending = None;
+        self.cycle = None;
+        self.turn = Player::X;
+        self.ongoing = true;
+        self.winner = None;
+        self.xscore = 0;
+        self.oscore = 0;
+    }
+
+    //////////////////////////////////
+    // Helpers
+    //////////////////////////////////
+
+    /// Score any completed lines after a collapse or classical move, ending
+    /// the game or passing the turn.
+    fn settle(&mut self) -> MoveOutcome {
+        let lines = self.board.lines();
+
+        if lines.is_empty() {
+            if self.board.open_cells().is_empty() {
+                self.ongoing = false;
+                self.log.draws += 1;
+
+                return MoveOutcome::Draw;
+            }
+
+            self.turn = self.turn.other();
+
+            return MoveOutcome::Switch;
+        }
+
+        let first = |player| {
+            lines
+                .iter()
+                .filter(|&&(owner, _)| owner == player)
+                .map(|&(_, newest)| newest)
+                .min()
+        };
+
+        match (first(Player::X), first(Player::O)) {
+            (Some(x), Some(o)) if x < o => {
+                self.xscore += 2;
+                self.oscore += 1;
+            }
+            (Some(_), Some(_)) => {
+                self.xscore += 1;
+                self.oscore += 2;
+            }
+            (Some(_), None) => self.xscore += 2,
+            _ => self.oscore += 2,
+        }
+
+        let winner = if self.xscore > self.oscore {
+            Player::X
+        } else {
+            Player::O
+        };
+
+        self.ongoing = false;
+        self.winner = Some(winner);
+
+        self.log.add_win(winner);
+
+        MoveOutcome::Win(winner)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spooky(game: &mut QuantumState, a: Position, b: Position) {
+        assert_eq!(game.play(a), MoveOutcome::Continue);
+        assert_eq!(game.play(b), MoveOutcome::Switch);
+    }
+
+    #[test]
+    fn test_spooky_move() {
+        let mut game = QuantumState::new();
+
+        spooky(&mut game, (0, 0), (1, 1));
+
+        let mark = Mark {
+            player: Player::X,
+            number: 1,
+        };
+
+        assert_eq!(game.board.cell((0, 0)).spooky, vec![mark]);
+        assert_eq!(game.board.cell((1, 1)).spooky, vec![mark]);
+        assert_eq!(game.turn, Player::O);
+        assert_eq!(game.cycle, None);
+    }
+
+    #[test]
+    fn test_invalid_plays() {
+        let mut game = QuantumState::new();
+
+        assert_eq!(game.play((3, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 0)), MoveOutcome::Continue);
+        assert_eq!(game.play((0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.collapse((0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 1)), MoveOutcome::Switch);
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        let mut board = QuantumBoard::new();
+
+        board.entangle(Player::X, ((0, 0), (1, 0)));
+        board.entangle(Player::O, ((1, 0), (2, 0)));
+
+        assert!(board.connected((0, 0), (2, 0)));
+        assert!(board.connected((0, 0), (1, 0)));
+        assert!(!board.connected((0, 0), (1, 1)));
+    }
+
+    #[test]
+    fn test_cycle_collapse() {
+        let mut game = QuantumState::new();
+
+        spooky(&mut game, (0, 0), (1, 0)); // X1
+        spooky(&mut game, (1, 0), (1, 1)); // O2
+
+        // X3 closes the cycle (0, 0) - (1, 0) - (1, 1)
+        spooky(&mut game, (1, 1), (0, 0));
+
+        assert_eq!(game.turn, Player::O);
+        assert!(game.cycle.is_some());
+        assert_eq!(game.play((2, 2)), MoveOutcome::NoChange);
+        assert_eq!(game.collapse((2, 2)), MoveOutcome::NoChange);
+
+        // O collapses X3 into (0, 0), forcing X1 into (1, 0) and O2 into
+        // (1, 1)
+        assert_eq!(game.collapse((0, 0)), MoveOutcome::Continue);
+        assert_eq!(game.turn, Player::O);
+
+        let classical = |pos| game.board.cell(pos).classical.unwrap();
+
+        assert_eq!(classical((0, 0)).number, 3);
+        assert_eq!(classical((1, 0)).number, 1);
+        assert_eq!(classical((1, 1)).number, 2);
+        assert!(game.board.cell((1, 1)).spooky.is_empty());
+    }
+
+    #[test]
+    fn test_collapse_propagates_to_attached_marks() {
+        let mut board = QuantumBoard::new();
+
+        let x1 = board.entangle(Player::X, ((0, 0), (1, 0)));
+        let o2 = board.entangle(Player::O, ((1, 0), (2, 0)));
+        let x3 = board.entangle(Player::X, ((2, 0), (2, 1)));
+
+        board.collapse(x1, (1, 0));
+
+        assert_eq!(board.cell((1, 0)).classical, Some(x1));
+        assert_eq!(board.cell((2, 0)).classical, Some(o2));
+        assert_eq!(board.cell((2, 1)).classical, Some(x3));
+        assert_eq!(board.cell((0, 0)).classical, None);
+        assert!(board.cell((0, 0)).spooky.is_empty());
+    }
+
+    #[test]
+    fn test_single_line_wins() {
+        let mut game = QuantumState::new();
+
+        spooky(&mut game, (0, 0), (0, 1)); // X1
+        spooky(&mut game, (2, 0), (2, 1)); // O2
+        spooky(&mut game, (0, 1), (0, 2)); // X3
+        spooky(&mut game, (2, 1), (2, 2)); // O4
+
+        // X5 closes the cycle (0, 0) - (0, 1) - (0, 2)
+        spooky(&mut game, (0, 2), (0, 0));
+
+        assert_eq!(game.collapse((0, 0)), MoveOutcome::Win(Player::X));
+        assert_eq!(game.xscore, 2);
+        assert_eq!(game.oscore, 0);
+        assert_eq!(game.log.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_simultaneous_lines_half_point() {
+        let mut game = QuantumState::new();
+
+        spooky(&mut game, (0, 0), (0, 1)); // X1
+        spooky(&mut game, (2, 0), (2, 1)); // O2
+        spooky(&mut game, (0, 1), (0, 2)); // X3
+        spooky(&mut game, (2, 1), (2, 2)); // O4
+
+        // X5 and O6 link both chains into one, and X7 closes the cycle
+        spooky(&mut game, (0, 2), (1, 0)); // X5
+        spooky(&mut game, (1, 0), (2, 2)); // O6
+        spooky(&mut game, (2, 0), (0, 0)); // X7
+
+        // Collapsing X7 into (0, 0) leaves X owning column 0 with marks
+        // 7, 1, 3 and O owning column 2 with marks 2, 4, 6
+        assert_eq!(game.collapse((0, 0)), MoveOutcome::Win(Player::O));
+
+        let classical = |pos| game.board.cell(pos).classical.unwrap();
+
+        assert_eq!(classical((0, 1)).number, 1);
+        assert_eq!(classical((0, 2)).number, 3);
+        assert_eq!(classical((2, 0)).number, 2);
+        assert_eq!(classical((2, 2)).number, 6);
+        assert_eq!(game.oscore, 2);
+        assert_eq!(game.xscore, 1);
+        assert_eq!(game.log.wins(Player::O), 1);
+    }
+
+    #[test]
+    fn test_last_cell_is_classical() {
+        let mut game = QuantumState::new();
+        let placed = [
+            ((0, 0), Player::X),
+            ((1, 0), Player::O),
+            ((2, 0), Player::X),
+            ((0, 1), Player::X),
+            ((1, 1), Player::O),
+            ((2, 1), Player::O),
+            ((0, 2), Player::O),
+            ((1, 2), Player::X),
+        ];
+
+        //  X   O   X
+        //  X   O   O
+        //  O   X   .
+
+        for &(pos, player) in placed.iter() {
+            game.board.place(player, pos);
+        }
+
+        assert_eq!(game.play((1, 1)), MoveOutcome::NoChange);
+        assert_eq!(game.play((2, 2)), MoveOutcome::Draw);
+        assert_eq!(game.board.cell((2, 2)).classical.unwrap().number, 9);
+        assert_eq!(game.log.draws, 1);
+    }
+}
@@ -0,0 +1,367 @@
+//! Qubic: three-dimensional 4x4x4 tic-tac-toe.
+
+use super::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Convenience Aliases
+//////////////////////////////////////////////////////////////////////////////
+
+/// Tuple of the form (column, row, layer) used for accessing cube cells.
+pub type CubePosition = (usize, usize, usize);
+
+/// Four cells forming a straight line through the cube.
+pub type CubeLine = [CubePosition; 4];
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Edge length of the cube.
+pub const SIZE: usize = 4;
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// Qubic playing cube, made of four stacked 4x4 layers.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Cube {
+    pub(crate) values: [[[Option<Player>; SIZE]; SIZE]; SIZE],
+}
+
+impl Cube {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a new 4x4x4 cube with each cell instantiated to `None`.
+    pub(crate) fn new() -> Self {
+        Self {
+            values: [[[None; SIZE]; SIZE]; SIZE],
+        }
+    }
+
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Value of the cell at the given position.
+    pub(crate) fn get(
+        &self,
+        (col, row, layer): CubePosition,
+    ) -> Option<Player> {
+        self.values[col][row][layer]
+    }
+
+    //////////////////////////////////
+    // Evaluation
+    //////////////////////////////////
+
+    /// Enumerate all 76 winning lines of the cube.
+    ///
+    /// Every line is a start cell plus one of the 13 directions whose first
+    /// non-zero component is positive, kept only when all four cells fit.
+    pub(crate) fn lines() -> Vec<CubeLine> {
+        let mut lines = Vec::new();
+        let steps = [-1, 0, 1];
+
+        for &dc in steps.iter() {
+            for &dr in steps.iter() {
+                for &dl in steps.iter() {
+                    let canonical = match (dc, dr, dl) {
+                        (0, 0, 0) => false,
+                        (0, 0, dl) => dl > 0,
+                        (0, dr, _) => dr > 0,
+                        (dc, _, _) => dc > 0,
+                    };
+
+                    if !canonical {
+                        continue;
+                    }
+
+                    for col in 0..SIZE {
+                        for row in 0..SIZE {
+                            for layer in 0..SIZE {
+                                let start = (col, row, layer);
+                                let step = (dc, dr, dl);
+
+                                if let Some(line) = line_from(start, step) {
+                                    lines.push(line);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        lines
+    }
+
+    /// Check for winners on the cube, either `Some(Player)` or `None`.
+    pub(crate) fn winner(&self) -> Option<Player> {
+        Self::lines().iter().find_map(|line| {
+            let first = self.get(line[0]);
+            let owned = line.iter().all(|&pos| self.get(pos) == first);
+
+            if first.is_some() && owned {
+                first
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Check for a full cube.
+    pub(crate) fn is_full(&self) -> bool {
+        self.values
+            .iter()
+            .flat_map(|plane| plane.iter())
+            .flat_map(|column| column.iter())
+            .all(Option::is_some)
+    }
+}
+
+/// Qubic game state.
+#[derive(Debug)]
+pub(crate) struct QubicState {
+    pub(crate) cube: Cube,
+    pub(crate) turn: Player,
+    pub(crate) ongoing: bool,
+    pub(crate) winner: Option<Player>,
+    pub(crate) log: Record,
+}
+
+impl QubicState {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Setup a new game, with X going first.
+    pub(crate) fn new() -> Self {
+        Self {
+            cube: Cube::new(),
+            turn: Player::X,
+            ongoing: true,
+            winner: None,
+            log: Record::new(),
+        }
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Attempt to make a move on the current cube.
+    pub(crate) fn play(
+        &mut self,
+        (col, row, layer): CubePosition,
+    ) -> MoveOutcome {
+        if !self.ongoing
+            || col >= SIZE
+            || row >= SIZE
+            || layer >= SIZE
+            || self.cube.values[col][row][layer].is_some()
+        {
+            return MoveOutcome::NoChange;
+        }
+
+        self.cube.values[col][row][layer] = Some(self.turn);
+
+        match self.cube.winner() {
+            Some(player) => {
+                self.ongoing = false;
+                self.winner = Some(player);
+
+                self.log.add_win(player);
+
+                MoveOutcome::Win(player)
+            }
+            None => {
+                if self.cube.is_full() {
+                    self.ongoing = false;
+                    self.log.draws += 1;
+
+                    MoveOutcome::Draw
+                } else {
+                    self.turn = self.turn.other();
+
+                    MoveOutcome::Switch
+                }
+            }
+        }
+    }
+
+    /// Reset everthing besides the game log.
+    pub(crate) fn reset(&mut self) {
+        self.cube = Cube::new();
+        self.turn = Player::X;
+        self.ongoing = true;
+        self.winner = None;
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Helpers
+//////////////////////////////////////////////////////////////////////////////
+
+/// Build the line of four cells from `start` along `step`, if it fits.
+fn line_from(
+    (col, row, layer): CubePosition,
+    (dc, dr, dl): (isize, isize, isize),
+) -> Option<CubeLine> {
+    let fits = |start: usize, step: isize| {
+        let end = start as isize + step * (SIZE as isize - 1);
+        end >= 0 && end < SIZE as isize
+    };
+
+    if !(fits(col, dc) && fits(row, dr) && fits(layer, dl)) {
+        return None;
+    }
+
+    let mut line = [(0, 0, 0); SIZE];
+
+    for (n, cell) in line.iter_mut().enumerate() {
+        let n = n as isize;
+
+        *cell = (
+            (col as isize + dc * n) as usize,
+            (row as isize + dr * n) as usize,
+            (layer as isize + dl * n) as usize,
+        );
+    }
+
+    Some(line)
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play_all(game: &mut QubicState, plays: &[CubePosition]) -> MoveOutcome {
+        let (last, rest) = plays.split_last().unwrap();
+
+        for &pos in rest {
+            assert_eq!(game.play(pos), MoveOutcome::Switch);
+        }
+
+        game.play(*last)
+    }
+
+    #[test]
+    fn test_line_count() {
+        let lines = Cube::lines();
+        let space_diagonals = lines
+            .iter()
+            .filter(|line| {
+                let (c0, r0, l0) = line[0];
+                let (c1, r1, l1) = line[1];
+                c0 != c1 && r0 != r1 && l0 != l1
+            })
+            .count();
+
+        assert_eq!(lines.len(), 76);
+        assert_eq!(space_diagonals, 4);
+    }
+
+    #[test]
+    fn test_space_diagonal_wins() {
+        let diagonals: [CubeLine; 4] = [
+            [(0, 0, 0), (1, 1, 1), (2, 2, 2), (3, 3, 3)],
+            [(3, 0, 0), (2, 1, 1), (1, 2, 2), (0, 3, 3)],
+            [(0, 3, 0), (1, 2, 1), (2, 1, 2), (3, 0, 3)],
+            [(0, 0, 3), (1, 1, 2), (2, 2, 1), (3, 3, 0)],
+        ];
+
+        for diagonal in diagonals.iter() {
+            let mut cube = Cube::new();
+
+            for &(col, row, layer) in diagonal.iter() {
+                assert_eq!(cube.winner(), None);
+                cube.values[col][row][layer] = Some(Player::O);
+            }
+
+            assert_eq!(cube.winner(), Some(Player::O));
+        }
+    }
+
+    #[test]
+    fn test_fast_win_for_x() {
+        let mut game = QubicState::new();
+        let plays = [
+            (0, 0, 0), // X
+            (0, 1, 0), // O
+            (1, 1, 1),
+            (0, 2, 0),
+            (2, 2, 2),
+            (0, 3, 0),
+            (3, 3, 3),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::X));
+        assert_eq!(game.log.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_pillar_win_for_o() {
+        let mut game = QubicState::new();
+        let plays = [
+            (0, 0, 0), // X
+            (2, 1, 0), // O
+            (1, 0, 0),
+            (2, 1, 1),
+            (3, 3, 3),
+            (2, 1, 2),
+            (0, 3, 3),
+            (2, 1, 3),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::O));
+    }
+
+    #[test]
+    fn test_face_diagonal_across_layers() {
+        let mut game = QubicState::new();
+        let plays = [
+            (1, 0, 0), // X
+            (0, 0, 0), // O
+            (1, 1, 1),
+            (3, 3, 3),
+            (1, 2, 2),
+            (2, 2, 2),
+            (1, 3, 3),
+        ];
+
+        assert_eq!(play_all(&mut game, &plays), MoveOutcome::Win(Player::X));
+    }
+
+    #[test]
+    fn test_invalid_plays() {
+        let mut game = QubicState::new();
+
+        assert_eq!(game.play((4, 0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 0, 4)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play((0, 0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.turn, Player::O);
+    }
+
+    #[test]
+    fn test_no_winner() {
+        let mut cube = Cube::new();
+
+        cube.values[0][0][0] = Some(Player::X);
+        cube.values[1][1][1] = Some(Player::X);
+        cube.values[2][2][2] = Some(Player::O);
+        cube.values[3][3][3] = Some(Player::X);
+        cube.values[0][1][2] = Some(Player::X);
+        cube.values[1][2][3] = Some(Player::X);
+
+        assert_eq!(cube.winner(), None);
+        assert!(!cube.is_full());
+    }
+}
@@ -0,0 +1,109 @@
+//! Elo ratings, updated after every recorded game.
+//!
+//! Games between more than two players are scored as a win for the winner
+//! against each other player, or as a draw between every pair.
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Rating of a profile before its first game.
+pub(crate) const INITIAL: i32 = 1500;
+
+/// Largest rating change from a single two-player game.
+const K_FACTOR: f64 = 32.0;
+
+/// Rating difference at which the stronger player is ten times as likely
+/// to win.
+const SCALE: f64 = 400.0;
+
+//////////////////////////////////////////////////////////////////////////////
+// Rating Functions
+//////////////////////////////////////////////////////////////////////////////
+
+/// Expected score of a player against a rival, between 0 and 1.
+pub(crate) fn expected(rating: i32, rival: i32) -> f64 {
+    1.0 / (1.0 + 10f64.powf(f64::from(rival - rating) / SCALE))
+}
+
+/// Ratings of the players in turn order after a game won by the player at
+/// `winner`, or drawn.
+pub(crate) fn update(ratings: &[i32], winner: Option<usize>) -> Vec<i32> {
+    let count = ratings.len();
+
+    if count < 2 {
+        return ratings.to_vec();
+    }
+
+    // Split the K-factor between each player's rivals
+    let k = K_FACTOR / (count - 1) as f64;
+    let mut changes = vec![0.0; count];
+
+    for i in 0..count {
+        for j in (i + 1)..count {
+            let score = match winner {
+                None => 0.5,
+                Some(w) if w == i => 1.0,
+                Some(w) if w == j => 0.0,
+                // Losers to a third player are not compared
+                Some(_) => continue,
+            };
+            let change = k * (score - expected(ratings[i], ratings[j]));
+
+            changes[i] += change;
+            changes[j] -= change;
+        }
+    }
+
+    ratings
+        .iter()
+        .zip(changes)
+        .map(|(&rating, change)| rating + change.round() as i32)
+        .collect()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_score() {
+        assert_eq!(expected(INITIAL, INITIAL), 0.5);
+        assert!((expected(1900, 1500) - 10.0 / 11.0).abs() < 1e-9);
+        assert!((expected(1600, 1500) + expected(1500, 1600) - 1.0) < 1e-9);
+    }
+
+    #[test]
+    fn test_win_between_equals() {
+        assert_eq!(update(&[1500, 1500], Some(0)), vec![1516, 1484]);
+        assert_eq!(update(&[1500, 1500], None), vec![1500, 1500]);
+    }
+
+    #[test]
+    fn test_draw_favours_underdog() {
+        let ratings = update(&[1700, 1500], None);
+
+        assert!(ratings[0] < 1700);
+        assert!(ratings[1] > 1500);
+        assert_eq!(ratings[0] + ratings[1], 3200);
+    }
+
+    #[test]
+    fn test_upset_gains_more() {
+        let favourite = update(&[1700, 1500], Some(0));
+        let underdog = update(&[1700, 1500], Some(1));
+
+        assert!(underdog[1] - 1500 > favourite[0] - 1700);
+    }
+
+    #[test]
+    fn test_multiplayer_win() {
+        let ratings = update(&[1500, 1500, 1500], Some(2));
+
+        assert_eq!(ratings, vec![1492, 1492, 1516]);
+    }
+}
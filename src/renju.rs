@@ -0,0 +1,273 @@
+//! Renju restrictions on the first player's (black's) moves.
+//!
+//! Black is always `Player::X`. A black move that makes exactly five is a win
+//! even when it would otherwise be a foul.
+
+use super::*;
+
+use mnk::{Grid, DIRECTIONS};
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// Reasons a black move may be forbidden.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Foul {
+    DoubleThree,
+    DoubleFour,
+    Overline,
+}
+
+impl Foul {
+    /// Catalog key of the foul's name.
+    pub(crate) fn key(&self) -> &'static str {
+        match self {
+            Foul::DoubleThree => "foul.double_three",
+            Foul::DoubleFour => "foul.double_four",
+            Foul::Overline => "foul.overline",
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Evaluation
+//////////////////////////////////////////////////////////////////////////////
+
+/// Check whether black playing the empty cell `pos` would be a foul.
+///
+/// Threes are counted without the full recursive renju definition: a three
+/// counts as open whenever some extension makes a straight four, even if that
+/// extension would itself be forbidden.
+pub(crate) fn foul_at(grid: &Grid, pos: Position) -> Option<Foul> {
+    let board = with_black(grid, pos);
+    let runs: Vec<usize> = DIRECTIONS
+        .iter()
+        .map(|&dir| board.run_length(pos, dir))
+        .collect();
+
+    if runs.contains(&5) {
+        return None;
+    }
+
+    if runs.iter().any(|&run| run > 5) {
+        return Some(Foul::Overline);
+    }
+
+    let fours: usize = DIRECTIONS
+        .iter()
+        .map(|&dir| count_fours(&board, pos, dir))
+        .sum();
+
+    if fours >= 2 {
+        return Some(Foul::DoubleFour);
+    }
+
+    let threes = DIRECTIONS
+        .iter()
+        .filter(|&&dir| {
+            !is_four(&board, pos, dir) && is_open_three(&board, pos, dir)
+        })
+        .count();
+
+    if threes >= 2 {
+        return Some(Foul::DoubleThree);
+    }
+
+    None
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Helpers
+//////////////////////////////////////////////////////////////////////////////
+
+/// Copy of the grid with a black stone added at `pos`.
+fn with_black(grid: &Grid, (col, row): Position) -> Grid {
+    let mut board = grid.clone();
+    board.values[col][row] = Some(Player::X);
+    board
+}
+
+/// Empty cells within `reach` steps of `pos` along `dir`, both ways.
+fn empty_cells(
+    board: &Grid,
+    pos: Position,
+    (dc, dr): (isize, isize),
+    reach: usize,
+) -> Vec<Position> {
+    let mut cells = Vec::new();
+
+    for &step in [(dc, dr), (-dc, -dr)].iter() {
+        let mut cursor = pos;
+
+        for _ in 0..reach {
+            match board.step(cursor, step) {
+                Some(next) => {
+                    if board.get(next).is_none() {
+                        cells.push(next);
+                    }
+                    cursor = next;
+                }
+                None => break,
+            }
+        }
+    }
+
+    cells
+}
+
+/// Cells completing the stone at `pos` into exactly five along `dir`.
+fn completions(
+    board: &Grid,
+    pos: Position,
+    dir: (isize, isize),
+) -> Vec<Position> {
+    empty_cells(board, pos, dir, 4)
+        .into_iter()
+        .filter(|&cell| with_black(board, cell).run_length(pos, dir) == 5)
+        .collect()
+}
+
+/// Number of distinct fours through the stone at `pos` along `dir`. A line
+/// like `X.XXX.X` holds two fours, each with its own completion point, but
+/// the two ends of a straight four `.XXXX.` complete the same four.
+fn count_fours(board: &Grid, pos: Position, dir: (isize, isize)) -> usize {
+    let cells = completions(board, pos, dir);
+
+    match cells.as_slice() {
+        // The ends of a straight four lie five steps apart
+        &[first, second] if steps_apart(board, first, second, dir, 5) => 1,
+        _ => cells.len(),
+    }
+}
+
+/// Check whether `to` lies `steps` steps from `from` along `dir`, either
+/// way, counting steps that wrap around a toroidal grid.
+fn steps_apart(
+    board: &Grid,
+    from: Position,
+    to: Position,
+    (dc, dr): (isize, isize),
+    steps: usize,
+) -> bool {
+    [(dc, dr), (-dc, -dr)].iter().any(|&step| {
+        (0..steps).try_fold(from, |cursor, _| board.step(cursor, step))
+            == Some(to)
+    })
+}
+
+/// Check whether the stone at `pos` is part of a four along `dir`.
+fn is_four(board: &Grid, pos: Position, dir: (isize, isize)) -> bool {
+    !completions(board, pos, dir).is_empty()
+}
+
+/// Check whether the stone at `pos` is part of an open three along `dir`,
+/// i.e. one more stone makes a straight four with two winning points.
+fn is_open_three(board: &Grid, pos: Position, dir: (isize, isize)) -> bool {
+    empty_cells(board, pos, dir, 4).into_iter().any(|cell| {
+        let extended = with_black(board, cell);
+        extended.run_length(pos, dir) == 4
+            && completions(&extended, pos, dir).len() == 2
+    })
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_with(black: &[Position], white: &[Position]) -> Grid {
+        let mut grid = Grid::new(15, 15);
+
+        for &(col, row) in black {
+            grid.values[col][row] = Some(Player::X);
+        }
+        for &(col, row) in white {
+            grid.values[col][row] = Some(Player::O);
+        }
+
+        grid
+    }
+
+    #[test]
+    fn test_overline() {
+        let grid = grid_with(&[(2, 7), (3, 7), (4, 7), (6, 7), (7, 7)], &[]);
+
+        assert_eq!(foul_at(&grid, (5, 7)), Some(Foul::Overline));
+    }
+
+    #[test]
+    fn test_five_beats_foul() {
+        // Exactly five horizontally, plus a second four vertically
+        let grid = grid_with(
+            &[(3, 7), (4, 7), (6, 7), (7, 7), (5, 4), (5, 5), (5, 6)],
+            &[],
+        );
+
+        assert_eq!(foul_at(&grid, (5, 7)), None);
+    }
+
+    #[test]
+    fn test_double_four() {
+        let grid = grid_with(
+            &[(2, 7), (3, 7), (4, 7), (5, 4), (5, 5), (5, 6)],
+            &[(1, 7), (5, 3)],
+        );
+
+        assert_eq!(foul_at(&grid, (5, 7)), Some(Foul::DoubleFour));
+    }
+
+    #[test]
+    fn test_double_four_in_one_line() {
+        // X.XXX.X with the move in the centre makes two fours
+        let grid = grid_with(&[(2, 7), (4, 7), (6, 7), (8, 7)], &[]);
+
+        assert_eq!(foul_at(&grid, (5, 7)), Some(Foul::DoubleFour));
+
+        // A straight four is a single four, however many ends it has
+        let grid = grid_with(&[(3, 7), (4, 7), (6, 7)], &[]);
+
+        assert_eq!(foul_at(&grid, (5, 7)), None);
+    }
+
+    #[test]
+    fn test_straight_four_across_wrapped_edge() {
+        // The four runs from column 13 round to column 1, open at 12 and 2
+        let mut grid = grid_with(&[(13, 7), (14, 7), (0, 7)], &[]);
+        grid.wrap = true;
+
+        assert_eq!(foul_at(&grid, (1, 7)), None);
+
+        // X.XXX.X across the edge still makes two fours
+        let mut grid = grid_with(&[(12, 7), (14, 7), (1, 7), (3, 7)], &[]);
+        grid.wrap = true;
+
+        assert_eq!(foul_at(&grid, (0, 7)), Some(Foul::DoubleFour));
+    }
+
+    #[test]
+    fn test_double_three() {
+        let grid = grid_with(&[(3, 7), (4, 7), (5, 5), (5, 6)], &[]);
+
+        assert_eq!(foul_at(&grid, (5, 7)), Some(Foul::DoubleThree));
+    }
+
+    #[test]
+    fn test_blocked_three_is_legal() {
+        // The horizontal three is capped by white and cannot become a
+        // straight four
+        let grid = grid_with(&[(3, 7), (4, 7), (5, 5), (5, 6)], &[(2, 7)]);
+
+        assert_eq!(foul_at(&grid, (5, 7)), None);
+    }
+
+    #[test]
+    fn test_four_three_is_legal() {
+        let grid = grid_with(&[(2, 7), (3, 7), (4, 7), (5, 5), (5, 6)], &[]);
+
+        assert_eq!(foul_at(&grid, (5, 7)), None);
+    }
+}
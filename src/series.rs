@@ -0,0 +1,144 @@
+//! Matches of several games, alternating who moves first.
+
+use super::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// How many game wins take a match.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SeriesFormat {
+    /// A majority of the given number of decisive games.
+    BestOf(u32),
+    /// The given number of wins.
+    FirstTo(u32),
+}
+
+impl SeriesFormat {
+    /// Game wins needed to take the match.
+    pub(crate) fn target(self) -> u32 {
+        match self {
+            SeriesFormat::BestOf(games) => games / 2 + 1,
+            SeriesFormat::FirstTo(wins) => wins,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Match state, scored apart from the lifetime game log.
+///
+/// Drawn games are replayed, so they never count towards a best-of total.
+#[derive(Clone, Debug)]
+pub(crate) struct Series {
+    pub(crate) format: SeriesFormat,
+    pub(crate) score: Record,
+    /// Games finished so far, drawn or not.
+    pub(crate) games: u32,
+    pub(crate) winner: Option<Player>,
+}
+
+impl Series {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Start a new match in the given format.
+    pub(crate) fn new(format: SeriesFormat) -> Self {
+        Self {
+            format,
+            score: Record::new(),
+            games: 0,
+            winner: None,
+        }
+    }
+
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Player moving first in the next game, alternating from X.
+    pub(crate) fn first(&self) -> Player {
+        if self.games % 2 == 1 {
+            Player::O
+        } else {
+            Player::X
+        }
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Score a finished game, won by `winner` or drawn.
+    pub(crate) fn record(&mut self, winner: Option<Player>) {
+        if self.winner.is_some() {
+            return;
+        }
+
+        self.games += 1;
+
+        match winner {
+            Some(player) => {
+                self.score.add_win(player);
+
+                if self.score.wins(player) >= self.format.target() {
+                    self.winner = Some(player);
+                }
+            }
+            None => self.score.draws += 1,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_player_alternates() {
+        let mut series = Series::new(SeriesFormat::BestOf(5));
+
+        assert_eq!(series.first(), Player::X);
+        series.record(Some(Player::X));
+        assert_eq!(series.first(), Player::O);
+        series.record(None);
+        assert_eq!(series.first(), Player::X);
+    }
+
+    #[test]
+    fn test_best_of_three() {
+        let mut series = Series::new(SeriesFormat::BestOf(3));
+
+        series.record(Some(Player::O));
+        series.record(None);
+        series.record(Some(Player::X));
+        assert_eq!(series.winner, None);
+
+        series.record(Some(Player::O));
+        assert_eq!(series.winner, Some(Player::O));
+        assert_eq!(series.score.wins(Player::O), 2);
+        assert_eq!(series.score.draws, 1);
+
+        // The decided match ignores further games
+        series.record(Some(Player::X));
+        assert_eq!(series.score.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_first_to() {
+        let mut series = Series::new(SeriesFormat::FirstTo(2));
+
+        series.record(Some(Player::X));
+        assert_eq!(series.winner, None);
+        series.record(Some(Player::X));
+        assert_eq!(series.winner, Some(Player::X));
+    }
+}
@@ -0,0 +1,270 @@
+//! Player display settings: names, colours and mark glyphs.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Local storage key the settings are saved under.
+pub(crate) const STORAGE_KEY: &str = "tic-tac-yew.settings";
+
+/// Longest display name, in characters.
+pub(crate) const MAX_NAME: usize = 16;
+
+/// Name shown for each player, indexed by turn order, when theirs is left
+/// blank.
+const DEFAULT_NAMES: [&str; 4] = ["Chi", "Omi", "Delta", "Psi"];
+
+/// Longest mark glyph, in characters, leaving room for emoji sequences.
+const MAX_GLYPH: usize = 4;
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// How one player is shown on screen.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct Appearance {
+    pub(crate) name: String,
+    /// CSS colour, e.g. `#ACF39D`.
+    pub(crate) colour: String,
+    pub(crate) glyph: String,
+}
+
+impl Appearance {
+    fn new(name: &str, colour: &str, glyph: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            colour: colour.to_string(),
+            glyph: glyph.to_string(),
+        }
+    }
+}
+
+/// Colour schemes of the whole interface.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize,
+)]
+pub(crate) enum Theme {
+    #[default]
+    Light,
+    Dark,
+    /// Saturated colours on black, overriding the players' own.
+    HighContrast,
+    /// A palette safe for colour vision deficiencies, with each player's
+    /// tiles also told apart by shape.
+    Colourblind,
+}
+
+impl Theme {
+    /// Every theme, in selector order.
+    pub(crate) const ALL: [Theme; 4] =
+        [Theme::Light, Theme::Dark, Theme::HighContrast, Theme::Colourblind];
+
+    /// CSS label applied to the whole interface.
+    pub(crate) fn class(self) -> &'static str {
+        match self {
+            Theme::Light => "theme-light",
+            Theme::Dark => "theme-dark",
+            Theme::HighContrast => "theme-contrast",
+            Theme::Colourblind => "theme-colourblind",
+        }
+    }
+
+    /// Catalog key of the theme's name.
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            Theme::Light => "theme.light",
+            Theme::Dark => "theme.dark",
+            Theme::HighContrast => "theme.contrast",
+            Theme::Colourblind => "theme.colourblind",
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Appearance of every player, indexed by turn order, and the interface
+/// language and theme.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct Settings {
+    pub(crate) sides: Vec<Appearance>,
+    #[serde(default)]
+    pub(crate) language: Language,
+    #[serde(default)]
+    pub(crate) theme: Theme,
+    /// Show on each classic tile the number of the move that marked it.
+    #[serde(default)]
+    pub(crate) move_numbers: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            sides: vec![
+                Appearance::new(DEFAULT_NAMES[0], "#ACF39D", "X"),
+                Appearance::new(DEFAULT_NAMES[1], "#00FFFF", "O"),
+                Appearance::new(DEFAULT_NAMES[2], "#F7A072", "Δ"),
+                Appearance::new(DEFAULT_NAMES[3], "#C3A6F5", "Ψ"),
+            ],
+            language: Language::English,
+            theme: Theme::Light,
+            move_numbers: false,
+        }
+    }
+}
+
+impl Settings {
+    //////////////////////////////////
+    // Accessors
+    //////////////////////////////////
+
+    /// Appearance of the given player.
+    pub(crate) fn side(&self, player: Player) -> &Appearance {
+        &self.sides[player.index()]
+    }
+
+    /// Name shown for the given player, falling back to the default one
+    /// when left blank.
+    pub(crate) fn name(&self, player: Player) -> &str {
+        match self.side(player).name.trim() {
+            "" => Settings::default_name(player),
+            name => name,
+        }
+    }
+
+    /// Name shown for the given player when theirs is left blank.
+    pub(crate) fn default_name(player: Player) -> &'static str {
+        DEFAULT_NAMES[player.index()]
+    }
+
+    /// Check whether restored settings cover every player.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.sides.len() == Player::ALL.len()
+    }
+
+    //////////////////////////////////
+    // Modification
+    //////////////////////////////////
+
+    /// Rename a player, keeping the name as typed so that it can be
+    /// cleared and retyped. Blank names are only replaced when shown.
+    pub(crate) fn set_name(&mut self, player: Player, name: &str) {
+        self.sides[player.index()].name =
+            name.chars().take(MAX_NAME).collect();
+    }
+
+    /// Recolour a player, ignoring anything but a hex colour.
+    pub(crate) fn set_colour(&mut self, player: Player, colour: &str) {
+        let hex = colour.trim_start_matches('#');
+
+        if (hex.len() == 3 || hex.len() == 6)
+            && hex.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            self.sides[player.index()].colour = format!("#{}", hex);
+        }
+    }
+
+    /// Change a player's mark glyph, which may be left blank.
+    pub(crate) fn set_glyph(&mut self, player: Player, glyph: &str) {
+        self.sides[player.index()].glyph =
+            glyph.trim().chars().take(MAX_GLYPH).collect();
+    }
+
+    //////////////////////////////////
+    // Rendering
+    //////////////////////////////////
+
+    /// CSS overriding the stylesheet's player colours, and drawing each
+    /// player's glyph on their marked tiles.
+    pub(crate) fn stylesheet(&self) -> String {
+        Player::ALL
+            .iter()
+            .map(|&player| {
+                let side = self.side(player);
+
+                // Escape every character so that no glyph can end the string
+                let glyph: String = side
+                    .glyph
+                    .chars()
+                    .map(|c| format!("\\{:x} ", c as u32))
+                    .collect();
+
+                format!(
+                    ".{tile}, .{team} {{ \
+                     color: {colour}; background-color: {colour}; \
+                     border-color: {colour}; }}\n\
+                     .{tile}:empty::after {{ content: \"{glyph}\"; }}\n",
+                    tile = tile_label(Some(player)),
+                    team = team_label(player),
+                    colour = side.colour,
+                    glyph = glyph,
+                )
+            })
+            .collect()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings() {
+        let settings = Settings::default();
+
+        assert!(settings.is_complete());
+        assert_eq!(settings.side(Player::X).name, "Chi");
+        assert_eq!(settings.side(Player::O).glyph, "O");
+    }
+
+    #[test]
+    fn test_set_name() {
+        let mut settings = Settings::default();
+
+        settings.set_name(Player::X, "  Alice  ");
+        assert_eq!(settings.name(Player::X), "Alice");
+
+        // A cleared name stays cleared while it is being retyped
+        settings.set_name(Player::X, "");
+        assert_eq!(settings.side(Player::X).name, "");
+        assert_eq!(settings.name(Player::X), "Chi");
+
+        settings.set_name(Player::O, "An extremely long display name");
+        assert_eq!(settings.side(Player::O).name.chars().count(), MAX_NAME);
+    }
+
+    #[test]
+    fn test_set_colour() {
+        let mut settings = Settings::default();
+
+        settings.set_colour(Player::X, "#ff8800");
+        assert_eq!(settings.side(Player::X).colour, "#ff8800");
+
+        settings.set_colour(Player::X, "red; display: none");
+        assert_eq!(settings.side(Player::X).colour, "#ff8800");
+    }
+
+    #[test]
+    fn test_stylesheet_escapes_glyphs() {
+        let mut settings = Settings::default();
+
+        settings.set_glyph(Player::O, "\"}");
+
+        let css = settings.stylesheet();
+
+        assert!(
+            css.contains(".o-tile:empty::after { content: \"\\22 \\7d \"; }")
+        );
+        assert!(css.contains(".x-tile, .chi { color: #ACF39D;"));
+    }
+}
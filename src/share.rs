@@ -0,0 +1,637 @@
+//! Shareable links reproducing a game from the URL fragment.
+//!
+//! A fragment names the variant, its rules, the player moving first and
+//! every move, e.g. `#variant=classic&rules=wild&first=o&moves=4x,0o,8x`.
+//! Cells are numbered 0 to 8 in reading order; a wild placement adds the
+//! mark placed, and a morris move joins two cells with `-`. A game lost on
+//! time ends with `&flag=` and the player whose flag fell.
+//!
+//! The m,n,k-games give their board size, rules, opening and number of
+//! players instead, e.g. `#variant=gomoku&size=15x15&rules=renju
+//! &opening=swap&players=2&moves=7.7,8.8,6.6,w`. Cells are written as
+//! column and row, and opening choices as `b`, `w` or `e`.
+
+use super::*;
+
+use mnk::Action;
+
+use stdweb::web::event::HashChangeEvent;
+use stdweb::web::{window, IEventTarget};
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Variant name of the classic board.
+const VARIANT: &str = "classic";
+
+/// Variant name of each m,n,k mode.
+const MNK_VARIANTS: [(Mode, &str); 3] = [
+    (Mode::Gomoku, "gomoku"),
+    (Mode::ConnectFour, "connect-four"),
+    (Mode::Multiplayer, "multiplayer"),
+];
+
+/// Widest or tallest m,n,k board a link may ask for.
+const MAX_SIZE: usize = 19;
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// One move of a classic game, as written to a link.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Move {
+    /// The given mark placed in an empty cell.
+    Place(Position, Player),
+    /// A morris piece moved from one cell to another.
+    Shift(Position, Position),
+}
+
+/// Game reproduced from a link.
+pub(crate) enum Shared {
+    Classic(GameState),
+    /// An m,n,k-game to be played in the given mode.
+    Mnk(Mode, MnkState),
+}
+
+/// Values of a fragment's keys, each given at most once.
+#[derive(Default)]
+struct Fields<'a> {
+    variant: Option<&'a str>,
+    rules: Option<&'a str>,
+    first: Option<&'a str>,
+    moves: Option<&'a str>,
+    flag: Option<&'a str>,
+    size: Option<&'a str>,
+    opening: Option<&'a str>,
+    players: Option<&'a str>,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Encoding
+//////////////////////////////////////////////////////////////////////////////
+
+/// Fragment reproducing the given game, without the leading `#`.
+pub(crate) fn encode(game: &GameState) -> String {
+    let rules = [
+        (game.misere, "misere"),
+        (game.wild, "wild"),
+        (game.vanishing, "vanishing"),
+        (game.morris, "morris"),
+        (game.morris && game.diagonal, "diagonal"),
+    ];
+    let rules: Vec<&str> = rules
+        .iter()
+        .filter(|&&(enabled, _)| enabled)
+        .map(|&(_, name)| name)
+        .collect();
+    let moves: Vec<String> = game
+        .history
+        .iter()
+        .map(|&played| match played {
+            Move::Place(pos, mark) if game.wild => {
+                format!("{}{}", cell_index(pos), mark_letter(mark))
+            }
+            Move::Place(pos, _) => cell_index(pos).to_string(),
+            Move::Shift(from, to) => {
+                format!("{}-{}", cell_index(from), cell_index(to))
+            }
+        })
+        .collect();
+
+    let mut fragment = format!(
+        "variant={}&rules={}&first={}&moves={}",
+        VARIANT,
+        rules.join(","),
+        mark_letter(game.first),
+        moves.join(","),
+    );
+
+    if let Some(loser) = game.flagged() {
+        fragment.push_str("&flag=");
+        fragment.push(mark_letter(loser));
+    }
+
+    fragment
+}
+
+/// Fragment reproducing the given game of an m,n,k mode, if the mode is
+/// one.
+pub(crate) fn encode_mnk(mode: Mode, game: &MnkState) -> Option<String> {
+    let &(_, variant) = MNK_VARIANTS.iter().find(|&&(m, _)| m == mode)?;
+    let rules = &game.rules;
+    let flags = [
+        (rules.exact, "exact"),
+        (rules.renju, "renju"),
+        (rules.wrap, "wrap"),
+    ];
+    let flags: Vec<&str> = flags
+        .iter()
+        .filter(|&&(enabled, _)| enabled)
+        .map(|&(_, name)| name)
+        .collect();
+    let opening = match rules.opening {
+        Opening::Free => "free",
+        Opening::Swap => "swap",
+        Opening::Swap2 => "swap2",
+    };
+    let moves: Vec<String> = game
+        .history
+        .iter()
+        .map(|&action| match action {
+            Action::Place((col, row)) => format!("{}.{}", col, row),
+            Action::Choose(Choice::Black) => "b".to_string(),
+            Action::Choose(Choice::White) => "w".to_string(),
+            Action::Choose(Choice::Extend) => "e".to_string(),
+        })
+        .collect();
+
+    Some(format!(
+        "variant={}&size={}x{}&rules={}&opening={}&players={}&moves={}",
+        variant,
+        rules.width,
+        rules.height,
+        flags.join(","),
+        opening,
+        rules.players,
+        moves.join(","),
+    ))
+}
+
+/// Game reproduced by replaying a fragment, with or without its leading
+/// `#`, if it is well formed and every move in it is legal.
+pub(crate) fn decode(fragment: &str) -> Option<Shared> {
+    let mut fields = Fields::default();
+
+    for pair in fragment.trim_start_matches('#').split('&') {
+        let mut parts = pair.splitn(2, '=');
+        let field = match parts.next() {
+            Some("variant") => &mut fields.variant,
+            Some("rules") => &mut fields.rules,
+            Some("first") => &mut fields.first,
+            Some("moves") => &mut fields.moves,
+            Some("flag") => &mut fields.flag,
+            Some("size") => &mut fields.size,
+            Some("opening") => &mut fields.opening,
+            Some("players") => &mut fields.players,
+            _ => return None,
+        };
+
+        if field.replace(parts.next()?).is_some() {
+            return None;
+        }
+    }
+
+    match fields.variant? {
+        VARIANT => decode_classic(&fields).map(Shared::Classic),
+        variant => {
+            let &(mode, _) =
+                MNK_VARIANTS.iter().find(|&&(_, name)| name == variant)?;
+
+            decode_mnk(mode, &fields).map(|game| Shared::Mnk(mode, game))
+        }
+    }
+}
+
+/// Classic game replayed from the fields of a fragment.
+fn decode_classic(fields: &Fields) -> Option<GameState> {
+    // The m,n,k rules mean nothing on the classic board
+    if fields.size.is_some()
+        || fields.opening.is_some()
+        || fields.players.is_some()
+    {
+        return None;
+    }
+
+    let mut game = GameState::new();
+    let (mut misere, mut wild, mut vanishing) = (false, false, false);
+    let (mut morris, mut diagonal) = (false, false);
+
+    for rule in fields.rules?.split(',').filter(|rule| !rule.is_empty()) {
+        match rule {
+            "misere" => misere = true,
+            "wild" => wild = true,
+            "vanishing" => vanishing = true,
+            "morris" => morris = true,
+            "diagonal" => diagonal = true,
+            _ => return None,
+        }
+    }
+
+    // Morris movement replaces the wild and vanishing rules
+    if (morris && (wild || vanishing)) || (diagonal && !morris) {
+        return None;
+    }
+
+    game.set_misere(misere);
+    game.set_wild(wild);
+    game.set_vanishing(vanishing);
+    game.set_morris(morris, diagonal);
+
+    game.first = parse_mark(fields.first?)?;
+    game.turn = game.first;
+
+    let moves = fields.moves?.split(',').filter(|played| !played.is_empty());
+
+    for played in moves {
+        let outcome = game.make(parse_move(played, game.wild)?);
+
+        if let MoveOutcome::NoChange | MoveOutcome::Forbidden(_) = outcome {
+            return None;
+        }
+    }
+
+    // Only the player to move can run out of time
+    if let Some(flag) = fields.flag {
+        if !game.ongoing || parse_mark(flag)? != game.turn {
+            return None;
+        }
+
+        game.flag_fall();
+    }
+
+    // Replayed results were never played here, so leave the record alone
+    game.log = Record::new();
+
+    Some(game)
+}
+
+/// Game of the given m,n,k mode replayed from the fields of a fragment,
+/// checking every move through `MnkState::play`.
+fn decode_mnk(mode: Mode, fields: &Fields) -> Option<MnkState> {
+    // Every seat moves in turn from X, and nobody plays on a clock
+    if fields.first.is_some() || fields.flag.is_some() {
+        return None;
+    }
+
+    let mut rules = match mode {
+        Mode::Gomoku => Rules::gomoku(),
+        Mode::ConnectFour => Rules::connect_four(),
+        _ => Rules::multiplayer(),
+    };
+
+    let mut size = fields.size?.splitn(2, 'x');
+    rules.width = size.next()?.parse().ok()?;
+    rules.height = size.next()?.parse().ok()?;
+
+    let (mut exact, mut renju, mut wrap) = (false, false, false);
+
+    for rule in fields.rules?.split(',').filter(|rule| !rule.is_empty()) {
+        match rule {
+            "exact" => exact = true,
+            "renju" => renju = true,
+            "wrap" => wrap = true,
+            _ => return None,
+        }
+    }
+
+    rules.exact = exact;
+    rules.renju = renju;
+    rules.wrap = wrap;
+    rules.opening = match fields.opening? {
+        "free" => Opening::Free,
+        "swap" => Opening::Swap,
+        "swap2" => Opening::Swap2,
+        _ => return None,
+    };
+    rules.players = fields.players?.parse().ok()?;
+
+    let sizes = 1..=MAX_SIZE;
+
+    if !sizes.contains(&rules.width)
+        || !sizes.contains(&rules.height)
+        || !(2..=Player::ALL.len()).contains(&rules.players)
+    {
+        return None;
+    }
+
+    let mut game = MnkState::new(rules);
+
+    let moves = fields.moves?.split(',').filter(|played| !played.is_empty());
+
+    for played in moves {
+        let action = parse_action(played)?;
+        let outcome = match action {
+            Action::Place(pos) => game.play(pos),
+            Action::Choose(choice) => game.choose(choice),
+        };
+
+        if let MoveOutcome::NoChange | MoveOutcome::Forbidden(_) = outcome {
+            return None;
+        }
+
+        // Pieces must be written where they came to rest under gravity
+        if game.history.last() != Some(&action) {
+            return None;
+        }
+    }
+
+    // Replayed results were never played here, so leave the record alone
+    game.log = Record::new();
+
+    Some(game)
+}
+
+/// Read one step of an m,n,k-game.
+fn parse_action(played: &str) -> Option<Action> {
+    let action = match played {
+        "b" => Action::Choose(Choice::Black),
+        "w" => Action::Choose(Choice::White),
+        "e" => Action::Choose(Choice::Extend),
+        _ => {
+            let mut parts = played.splitn(2, '.');
+            let col = parts.next()?.parse().ok()?;
+            let row = parts.next()?.parse().ok()?;
+
+            Action::Place((col, row))
+        }
+    };
+
+    Some(action)
+}
+
+/// Read one move, which must name the mark placed exactly under wild
+/// rules.
+fn parse_move(played: &str, wild: bool) -> Option<Move> {
+    let mut chars = played.chars();
+    let from = cell_position(chars.next()?)?;
+
+    let played = match (chars.next(), chars.next()) {
+        (None, _) if !wild => Move::Place(from, Player::X),
+        (Some('-'), Some(to)) => Move::Shift(from, cell_position(to)?),
+        (Some('x'), None) if wild => Move::Place(from, Player::X),
+        (Some('o'), None) if wild => Move::Place(from, Player::O),
+        _ => return None,
+    };
+
+    match chars.next() {
+        Some(_) => None,
+        None => Some(played),
+    }
+}
+
+/// Digit naming a cell, counting from 0 in reading order.
+fn cell_index((col, row): Position) -> usize {
+    row * 3 + col
+}
+
+/// Cell named by a digit, if it is on the board.
+fn cell_position(digit: char) -> Option<Position> {
+    digit
+        .to_digit(10)
+        .map(|index| index as usize)
+        .filter(|&index| index < 9)
+        .map(|index| (index % 3, index / 3))
+}
+
+/// Classic mark named by a letter.
+fn parse_mark(letter: &str) -> Option<Player> {
+    match letter {
+        "x" => Some(Player::X),
+        "o" => Some(Player::O),
+        _ => None,
+    }
+}
+
+/// Letter naming a classic mark.
+fn mark_letter(mark: Player) -> char {
+    match mark {
+        Player::O => 'o',
+        _ => 'x',
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Browser Location
+//////////////////////////////////////////////////////////////////////////////
+
+/// Current URL fragment, without the leading `#`.
+pub(crate) fn fragment() -> Option<String> {
+    let hash = window().location()?.hash().ok()?;
+
+    Some(hash.trim_start_matches('#').to_string())
+}
+
+/// Replace the URL fragment without adding a history entry, or remove it.
+pub(crate) fn set_fragment(fragment: Option<&str>) {
+    let location = match window().location() {
+        Some(location) => location,
+        None => return,
+    };
+    let url = match fragment {
+        Some(fragment) => format!("#{}", fragment),
+        None => {
+            let path = location.pathname().unwrap_or_default();
+            let search = location.search().unwrap_or_default();
+
+            path + &search
+        }
+    };
+
+    let _ = window().history().replace_state((), "", Some(&url));
+}
+
+/// Send a message whenever the fragment is changed by hand or by a link.
+pub(crate) fn on_fragment_change(callback: Callback<()>) {
+    window().add_event_listener(move |_: HashChangeEvent| {
+        callback.emit(());
+    });
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classic(fragment: &str) -> Option<GameState> {
+        match decode(fragment)? {
+            Shared::Classic(game) => Some(game),
+            Shared::Mnk(..) => None,
+        }
+    }
+
+    fn mnk(fragment: &str) -> Option<(Mode, MnkState)> {
+        match decode(fragment)? {
+            Shared::Mnk(mode, game) => Some((mode, game)),
+            Shared::Classic(_) => None,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut game = GameState::new();
+
+        game.set_misere(true);
+        game.play((1, 1));
+        game.play((0, 0));
+
+        let fragment = encode(&game);
+
+        assert_eq!(fragment, "variant=classic&rules=misere&first=x&moves=4,0");
+
+        let shared = classic(&format!("#{}", fragment)).unwrap();
+
+        assert!(shared.misere);
+        assert_eq!(shared.board.values, game.board.values);
+        assert_eq!(shared.turn, Player::X);
+        assert_eq!(encode(&shared), fragment);
+    }
+
+    #[test]
+    fn test_wild_and_morris_moves() {
+        let shared =
+            classic("variant=classic&rules=wild&first=o&moves=4x,0o").unwrap();
+
+        assert_eq!(shared.first, Player::O);
+        assert_eq!(shared.board.values[1][1], Some(Player::X));
+        assert_eq!(shared.board.values[0][0], Some(Player::O));
+
+        let fragment = "variant=classic&rules=morris&first=x\
+                        &moves=0,1,4,8,6,2,4-3";
+        let shared = classic(fragment).unwrap();
+
+        assert_eq!(shared.winner, Some(Player::X));
+        assert_eq!(encode(&shared), fragment);
+
+        // The replayed win is not counted as one played here
+        assert_eq!(shared.log.wins(Player::X), 0);
+    }
+
+    #[test]
+    fn test_flag_fall() {
+        let mut game = GameState::new();
+
+        game.set_time_control(Some(TimeControl::new(1, 0, Bonus::Increment)));
+        game.play((1, 1));
+        game.tick(60_000);
+
+        let fragment = encode(&game);
+
+        assert_eq!(fragment, "variant=classic&rules=&first=x&moves=4&flag=o");
+
+        let shared = classic(&fragment).unwrap();
+
+        assert!(!shared.ongoing);
+        assert_eq!(shared.winner, Some(Player::X));
+        assert_eq!(shared.log.wins(Player::X), 0);
+
+        // Only the player to move can lose on time
+        assert!(
+            classic("variant=classic&rules=&first=x&moves=4&flag=x").is_none()
+        );
+    }
+
+    #[test]
+    fn test_illegal_links_are_rejected() {
+        let base = "variant=classic&rules=&first=x&moves=";
+
+        assert!(classic(base).is_some());
+
+        // Occupied cell, off the board, mark outside wild rules
+        assert!(classic(&format!("{}4,4", base)).is_none());
+        assert!(classic(&format!("{}9", base)).is_none());
+        assert!(classic(&format!("{}4o", base)).is_none());
+
+        // Moves after the game is won
+        assert!(classic(&format!("{}0,3,1,4,2,5", base)).is_none());
+
+        assert!(decode("variant=gomoku&rules=&first=x&moves=").is_none());
+        assert!(classic("variant=classic&rules=wild,morris&first=x&moves=")
+            .is_none());
+        assert!(classic("variant=classic&first=x&moves=").is_none());
+    }
+
+    #[test]
+    fn test_mnk_round_trip() {
+        let mut game = MnkState::new(Rules {
+            renju: true,
+            opening: Opening::Swap,
+            ..Rules::gomoku()
+        });
+
+        for &pos in [(7, 7), (8, 8), (6, 6)].iter() {
+            game.play(pos);
+        }
+        game.choose(Choice::White);
+        game.play((9, 9));
+
+        let fragment = encode_mnk(Mode::Gomoku, &game).unwrap();
+
+        assert_eq!(
+            fragment,
+            "variant=gomoku&size=15x15&rules=renju&opening=swap&players=2\
+             &moves=7.7,8.8,6.6,w,9.9"
+        );
+
+        let (mode, shared) = mnk(&format!("#{}", fragment)).unwrap();
+
+        assert_eq!(mode, Mode::Gomoku);
+        assert_eq!(shared.rules, game.rules);
+        assert_eq!(shared.grid.values, game.grid.values);
+        assert_eq!(shared.swapped, game.swapped);
+        assert_eq!(shared.turn, game.turn);
+        assert_eq!(encode_mnk(mode, &shared).unwrap(), fragment);
+
+        // Only the m,n,k modes have links of this kind
+        assert_eq!(encode_mnk(Mode::Ultimate, &game), None);
+    }
+
+    #[test]
+    fn test_mnk_wins_and_gravity() {
+        let fragment = "variant=connect-four&size=7x6&rules=wrap&opening=free\
+                        &players=2&moves=0.5,1.5,0.4,1.4,0.3,1.3,0.2";
+        let (mode, shared) = mnk(fragment).unwrap();
+
+        assert_eq!(mode, Mode::ConnectFour);
+        assert!(shared.rules.gravity && shared.rules.wrap);
+        assert_eq!(shared.winner, Some(Player::X));
+        assert_eq!(shared.log.wins(Player::X), 0);
+
+        // Pieces must be written where they land
+        let base = "variant=connect-four&size=7x6&rules=&opening=free\
+                    &players=2&moves=";
+
+        assert!(mnk(&format!("{}3.5", base)).is_some());
+        assert!(mnk(&format!("{}3.0", base)).is_none());
+
+        let (_, shared) = mnk(
+            "variant=multiplayer&size=10x10&rules=&opening=free&players=4\
+             &moves=0.0,1.1,2.2,3.3",
+        )
+        .unwrap();
+
+        assert_eq!(shared.rules.players, 4);
+        assert_eq!(shared.turn, Player::X);
+    }
+
+    #[test]
+    fn test_illegal_mnk_links_are_rejected() {
+        let base = "variant=gomoku&size=15x15&rules=&opening=free&players=2";
+
+        assert!(mnk(&format!("{}&moves=", base)).is_some());
+
+        // Occupied cell, off the board, a choice outside any opening
+        assert!(mnk(&format!("{}&moves=7.7,7.7", base)).is_none());
+        assert!(mnk(&format!("{}&moves=15.0", base)).is_none());
+        assert!(mnk(&format!("{}&moves=7.7,b", base)).is_none());
+
+        // A renju foul for black
+        let renju = "variant=gomoku&size=15x15&rules=renju&opening=free\
+                     &players=2&moves=3.7,0.0,4.7,0.1,5.5,0.2,5.6,0.3,5.7";
+        assert!(mnk(renju).is_none());
+
+        // Classic fields, bad sizes and repeated keys
+        assert!(mnk(&format!("{}&moves=&first=x", base)).is_none());
+        for &size in ["99x99", "0x15", "15"].iter() {
+            let resized = base.replace("15x15", size);
+            assert!(mnk(&format!("{}&moves=", resized)).is_none());
+        }
+        assert!(mnk(&format!("{}&moves=&moves=", base)).is_none());
+        assert!(classic("variant=classic&rules=&first=x&moves=&players=2")
+            .is_none());
+    }
+}
@@ -0,0 +1,496 @@
+//! Computer players for the classic board, rated like any profile.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+//////////////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////////////
+
+/// Plies searched at most before a position is scored as even, which only
+/// cuts the search short under vanishing and morris rules.
+const SEARCH_DEPTH: u32 = 9;
+
+/// Positions searched at most for one move. The search goes one ply deeper
+/// at a time while the budget lasts, so that wild and vanishing rules,
+/// which multiply the positions, cannot stall the page.
+const NODE_BUDGET: u32 = 40_000;
+
+/// Score of a win found at the root, less one for every ply to reach it.
+const WIN_SCORE: i32 = 100;
+
+//////////////////////////////////////////////////////////////////////////////
+// Auxiliary Structures
+//////////////////////////////////////////////////////////////////////////////
+
+/// Everything deciding how a game may go on from a position, so that
+/// positions reached by different orders of moves are only searched once.
+#[derive(Eq, Hash, PartialEq)]
+struct Key {
+    cells: Cells,
+    turn: Player,
+    /// Moves made so far, which also tells how deep the search has gone.
+    moves: u32,
+    /// Cells each player placed in, oldest first, as decimal digits under
+    /// vanishing rules.
+    placed: u32,
+    /// Occurrences of earlier positions under morris rules, sorted.
+    repetitions: Vec<(u32, u32)>,
+}
+
+impl Key {
+    /// Key of the given position.
+    fn of(game: &GameState) -> Self {
+        let mut placed = 0;
+
+        if game.vanishing {
+            for player in [Player::X, Player::O].iter() {
+                let cells = game.placed.get(player);
+
+                for slot in 0..VANISHING_MARKS {
+                    let digit = match cells.and_then(|cells| cells.get(slot)) {
+                        Some(&(col, row)) => col * 3 + row + 1,
+                        None => 0,
+                    };
+
+                    placed = placed * 10 + digit as u32;
+                }
+            }
+        }
+
+        let mut repetitions: Vec<(u32, u32)> = Vec::new();
+
+        if game.morris {
+            repetitions = game
+                .repetitions
+                .iter()
+                .map(|(&(cells, turn), &count)| {
+                    (cells_code(cells, turn), count)
+                })
+                .collect();
+            repetitions.sort_unstable();
+        }
+
+        Self {
+            cells: game.board.values,
+            turn: game.turn,
+            moves: game.moves,
+            placed,
+            repetitions,
+        }
+    }
+}
+
+/// Value of a position searched within some window, which is exact only
+/// if it fell inside the window.
+#[derive(Clone, Copy)]
+enum Bound {
+    Exact(i32),
+    /// The position is worth at least this much.
+    Lower(i32),
+    /// The position is worth at most this much.
+    Upper(i32),
+}
+
+/// One pass of the search, down to a fixed depth.
+struct Search {
+    /// Plies searched before a position is scored as even.
+    limit: u32,
+    /// Positions the pass may still search.
+    budget: u32,
+    /// Whether any line was scored as even for reaching `limit`.
+    cut: bool,
+    /// Positions already searched in this pass. Each key is only ever met
+    /// at one depth, so scores counted from the root can be reused.
+    table: HashMap<Key, Bound>,
+}
+
+impl Search {
+    /// Setup a pass searching `limit` plies deep, within `budget`.
+    fn new(limit: u32, budget: u32) -> Self {
+        Self {
+            limit,
+            budget,
+            cut: false,
+            table: HashMap::new(),
+        }
+    }
+
+    /// Best of the given moves, winning soonest and losing latest, unless
+    /// the budget runs out first. Ties go to the earliest move.
+    fn best_move(&mut self, root: &GameState, moves: &[Move]) -> Option<Move> {
+        let mut scored = Vec::new();
+
+        for &played in moves {
+            let window = (-WIN_SCORE, WIN_SCORE);
+            scored.push((played, self.score(root, played, 1, window)?));
+        }
+
+        let best = scored.iter().map(|&(_, score)| score).max()?;
+
+        scored
+            .into_iter()
+            .find(|&(_, score)| score == best)
+            .map(|(played, _)| played)
+    }
+
+    /// Score of a move for the player making it, searched `depth` plies
+    /// from the root with alpha-beta pruning between `alpha` and `beta`,
+    /// unless the budget runs out first.
+    fn score(
+        &mut self,
+        game: &GameState,
+        played: Move,
+        depth: u32,
+        (alpha, beta): (i32, i32),
+    ) -> Option<i32> {
+        self.budget = self.budget.checked_sub(1)?;
+
+        let mover = game.turn;
+        let mut next = game.clone();
+
+        next.make(played);
+
+        if !next.ongoing {
+            return Some(match next.winner {
+                Some(winner) if winner == mover => WIN_SCORE - depth as i32,
+                Some(_) => depth as i32 - WIN_SCORE,
+                None => 0,
+            });
+        }
+
+        // The move is worth as little as the opponent's best reply allows
+        let (low, high) = (-beta, -alpha);
+        let key = Key::of(&next);
+
+        match self.table.get(&key) {
+            Some(&Bound::Exact(best)) => return Some(-best),
+            Some(&Bound::Lower(best)) if best >= high => return Some(-best),
+            Some(&Bound::Upper(best)) if best <= low => return Some(-best),
+            _ => {}
+        }
+
+        if depth >= self.limit {
+            self.cut = true;
+            return Some(0);
+        }
+
+        let replies = legal_moves(&next);
+
+        if replies.is_empty() {
+            return Some(0);
+        }
+
+        let mut best = low;
+
+        for reply in replies {
+            let window = (best, high);
+            best = best.max(self.score(&next, reply, depth + 1, window)?);
+
+            if best >= high {
+                break;
+            }
+        }
+
+        let bound = if best <= low {
+            Bound::Upper(best)
+        } else if best >= high {
+            Bound::Lower(best)
+        } else {
+            Bound::Exact(best)
+        };
+
+        self.table.insert(key, bound);
+
+        Some(-best)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// How a computer player picks its moves.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub(crate) enum Strategy {
+    /// Any legal move, with no lookahead.
+    #[serde(rename = "ai:random")]
+    Random,
+    /// The best move found by searching every reply, winning soonest and
+    /// losing latest.
+    #[serde(rename = "ai:minimax")]
+    Minimax,
+}
+
+impl Strategy {
+    /// Every strategy, in seating order.
+    pub(crate) const ALL: [Strategy; 2] =
+        [Strategy::Random, Strategy::Minimax];
+
+    /// Name the strategy is saved under, which no profile may take.
+    pub(crate) fn id(self) -> &'static str {
+        match self {
+            Strategy::Random => "ai:random",
+            Strategy::Minimax => "ai:minimax",
+        }
+    }
+
+    /// Catalog key of the strategy's name.
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            Strategy::Random => "strategy.random",
+            Strategy::Minimax => "strategy.minimax",
+        }
+    }
+
+    /// Move to make for the player to move, if the game is still on. The
+    /// seed picks among equally good moves.
+    pub(crate) fn choose(self, game: &GameState, seed: u64) -> Option<Move> {
+        let moves = legal_moves(game);
+
+        if !game.ongoing || moves.is_empty() {
+            return None;
+        }
+
+        // Start from a seeded move, so that ties go a different way
+        let count = moves.len();
+        let mut moves = moves
+            .into_iter()
+            .cycle()
+            .skip(seed as usize % count)
+            .take(count);
+
+        match self {
+            Strategy::Random => moves.next(),
+            Strategy::Minimax => {
+                let root = search_root(game);
+                let moves: Vec<Move> = moves.collect();
+                let mut best = moves[0];
+                let mut budget = NODE_BUDGET;
+
+                // Keep the choice of the deepest pass finished in time
+                for limit in 1..=SEARCH_DEPTH {
+                    let mut search = Search::new(limit, budget);
+
+                    match search.best_move(&root, &moves) {
+                        Some(played) => best = played,
+                        None => break,
+                    }
+
+                    // Deeper passes see nothing new once no line was cut
+                    if !search.cut {
+                        break;
+                    }
+
+                    budget = search.budget;
+                }
+
+                Some(best)
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Search
+//////////////////////////////////////////////////////////////////////////////
+
+/// Every move the player to move may make.
+fn legal_moves(game: &GameState) -> Vec<Move> {
+    if game.must_shift() {
+        return POSITIONS
+            .iter()
+            .filter(|&&(col, row)| {
+                game.board.values[col][row] == Some(game.turn)
+            })
+            .flat_map(|&from| {
+                POSITIONS
+                    .iter()
+                    .filter(move |&&(col, row)| {
+                        game.board.values[col][row].is_none()
+                            && game.is_adjacent(from, (col, row))
+                    })
+                    .map(move |&to| Move::Shift(from, to))
+            })
+            .collect();
+    }
+
+    let marks = if game.wild {
+        vec![Player::X, Player::O]
+    } else {
+        vec![game.turn]
+    };
+
+    POSITIONS
+        .iter()
+        .filter(|&&(col, row)| game.board.values[col][row].is_none())
+        .flat_map(|&pos| marks.iter().map(move |&mark| Move::Place(pos, mark)))
+        .collect()
+}
+
+/// Number telling apart every board and player to move.
+fn cells_code(cells: Cells, turn: Player) -> u32 {
+    cells
+        .iter()
+        .flat_map(|column| column.iter())
+        .fold(turn.index() as u32, |code, &value| {
+            code * 3 + value.map_or(0, |player| player.index() as u32 + 1)
+        })
+}
+
+/// Copy of the game holding only what the rules need, so that copying it
+/// at every node of the search stays cheap.
+fn search_root(game: &GameState) -> GameState {
+    GameState {
+        history: Vec::new(),
+        clock: Clock::new(None),
+        series: None,
+        log: Record::new(),
+        ..game.clone()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_plays_legal_moves() {
+        let mut game = GameState::new();
+
+        for seed in 0.. {
+            match Strategy::Random.choose(&game, seed * 7) {
+                Some(played) => {
+                    assert_ne!(game.make(played), MoveOutcome::NoChange)
+                }
+                None => break,
+            }
+        }
+
+        assert!(!game.ongoing);
+    }
+
+    #[test]
+    fn test_minimax_takes_win_and_blocks() {
+        let mut game = GameState::new();
+
+        //  X   X   .
+        //  O   O   .
+        //  .   .   .
+
+        for &pos in [(0, 0), (0, 1), (1, 0), (1, 1)].iter() {
+            game.play(pos);
+        }
+
+        let played = Strategy::Minimax.choose(&game, 0);
+        assert_eq!(played, Some(Move::Place((2, 0), Player::X)));
+
+        // O has no win of its own and must block X's column
+        let mut game = GameState::new();
+
+        for &pos in [(0, 0), (1, 1), (0, 1)].iter() {
+            game.play(pos);
+        }
+
+        let played = Strategy::Minimax.choose(&game, 3);
+        assert_eq!(played, Some(Move::Place((0, 2), Player::O)));
+    }
+
+    #[test]
+    fn test_minimax_never_loses() {
+        // Every reply to every opening still draws against perfect play
+        for opening in POSITIONS.iter() {
+            let mut game = GameState::new();
+
+            game.play(*opening);
+
+            while game.ongoing {
+                let played = Strategy::Minimax.choose(&game, 0).unwrap();
+                game.make(played);
+            }
+
+            assert_eq!(game.winner, None);
+        }
+    }
+
+    #[test]
+    fn test_minimax_avoids_lines_in_misere() {
+        //  X   X   .
+        //  .   O   .
+        //  .   .   O
+
+        for &misere in [false, true].iter() {
+            let mut game = GameState::new();
+
+            game.set_misere(misere);
+
+            for &pos in [(0, 0), (1, 1), (1, 0), (2, 2)].iter() {
+                game.play(pos);
+            }
+
+            for seed in 0..9 {
+                let played = Strategy::Minimax.choose(&game, seed).unwrap();
+                let completes = played == Move::Place((2, 0), Player::X);
+
+                // The row wins outright, but loses under misère rules
+                assert_eq!(completes, !misere);
+
+                let mut next = game.clone();
+                next.make(played);
+                assert_ne!(next.winner, Some(Player::O));
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_stays_within_budget() {
+        let mut game = GameState::new();
+
+        game.set_wild(true);
+        game.set_vanishing(true);
+
+        // Far too many lines to search to full depth for one move
+        let moves = legal_moves(&game);
+        let mut search = Search::new(SEARCH_DEPTH, NODE_BUDGET);
+
+        assert_eq!(search.best_move(&game, &moves), None);
+        assert!(Strategy::Minimax.choose(&game, 0).is_some());
+
+        // Shallower passes still see a win in one
+        for &pos in [(0, 0), (1, 0), (1, 1), (2, 1)].iter() {
+            game.make(Move::Place(pos, Player::X));
+        }
+
+        let played = Strategy::Minimax.choose(&game, 0).unwrap();
+        let mut next = game.clone();
+
+        assert_eq!(next.make(played), MoveOutcome::Win(Player::X));
+    }
+
+    #[test]
+    fn test_strategies_move_morris_pieces() {
+        let mut game = GameState::new();
+
+        game.set_morris(true, false);
+
+        for &pos in [(0, 0), (1, 0), (1, 1), (2, 2), (0, 2), (2, 0)].iter() {
+            game.play(pos);
+        }
+
+        // X must move a piece, and only the centre one completes a line
+        let played = Strategy::Minimax.choose(&game, 0);
+        assert_eq!(played, Some(Move::Shift((1, 1), (0, 1))));
+
+        match Strategy::Random.choose(&game, 5) {
+            Some(Move::Shift(..)) => {}
+            played => panic!("expected a morris move, got {:?}", played),
+        }
+    }
+}
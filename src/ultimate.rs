@@ -0,0 +1,258 @@
+//! Ultimate tic-tac-toe: a 3x3 grid of boards played as a single game.
+
+use super::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Primary Structure
+//////////////////////////////////////////////////////////////////////////////
+
+/// Ultimate tic-tac-toe game state.
+///
+/// The cell played within a sub-board picks the sub-board the opponent must
+/// play in next. Winning a sub-board claims the matching cell of the meta
+/// board, and three claimed cells in a row win the game.
+#[derive(Debug)]
+pub(crate) struct UltimateState {
+    pub(crate) boards: [[Board; 3]; 3],
+    pub(crate) meta: Board,
+    pub(crate) target: Option<Position>,
+    pub(crate) turn: Player,
+    pub(crate) ongoing: bool,
+    pub(crate) winner: Option<Player>,
+    pub(crate) log: Record,
+}
+
+impl UltimateState {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Setup a new game, with X going first and free to play anywhere.
+    pub(crate) fn new() -> Self {
+        Self {
+            boards: [[Board::new(); 3]; 3],
+            meta: Board::new(),
+            target: None,
+            turn: Player::X,
+            ongoing: true,
+            winner: None,
+            log: Record::new(),
+        }
+    }
+
+    //////////////////////////////////
+    // Evaluation
+    //////////////////////////////////
+
+    /// Check whether a sub-board can still receive moves, i.e. it has been
+    /// neither won nor filled.
+    pub(crate) fn is_open(&self, (col, row): Position) -> bool {
+        self.meta.values[col][row].is_none()
+            && !self.boards[col][row].is_full()
+    }
+
+    /// Check whether the active player may play in the given sub-board.
+    pub(crate) fn is_playable(&self, outer: Position) -> bool {
+        self.ongoing
+            && self.is_open(outer)
+            && (self.target.is_none() || self.target == Some(outer))
+    }
+
+    //////////////////////////////////
+    // Interaction
+    //////////////////////////////////
+
+    /// Attempt to play cell `inner` of sub-board `outer`.
+    ///
+    /// When the sub-board the move points to is won or full, the opponent may
+    /// choose any open sub-board instead.
+    pub(crate) fn play(
+        &mut self,
+        outer: Position,
+        (col, row): Position,
+    ) -> MoveOutcome {
+        let (outer_col, outer_row) = outer;
+
+        if outer_col >= 3
+            || outer_row >= 3
+            || col >= 3
+            || row >= 3
+            || !self.is_playable(outer)
+        {
+            return MoveOutcome::NoChange;
+        }
+
+        let board = &mut self.boards[outer_col][outer_row];
+
+        if board.values[col][row].is_some() {
+            return MoveOutcome::NoChange;
+        }
+
+        board.values[col][row] = Some(self.turn);
+
+        if let Some(player) = board.winner() {
+            self.meta.values[outer_col][outer_row] = Some(player);
+        }
+
+        if let Some(player) = self.meta.winner() {
+            self.ongoing = false;
+            self.winner = Some(player);
+
+            self.log.add_win(player);
+
+            MoveOutcome::Win(player)
+        } else if !self.any_open() {
+            self.ongoing = false;
+            self.log.draws += 1;
+
+            MoveOutcome::Draw
+        } else {
+            self.target = if self.is_open((col, row)) {
+                Some((col, row))
+            } else {
+                None
+            };
+            self.turn = self.turn.other();
+
+            MoveOutcome::Switch
+        }
+    }
+
+    /// Reset everthing besides the game log.
+    pub(crate) fn reset(&mut self) {
+        self.boards = [[Board::new(); 3]; 3];
+        self.meta = Board::new();
+        self.target = None;
+        self.turn = Player::X;
+        self.ongoing = true;
+        self.winner = None;
+    }
+
+    //////////////////////////////////
+    // Helpers
+    //////////////////////////////////
+
+    /// Check whether any sub-board can still receive moves.
+    fn any_open(&self) -> bool {
+        (0..3).any(|col| (0..3).any(|row| self.is_open((col, row))))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_sends_opponent() {
+        let mut game = UltimateState::new();
+
+        assert_eq!(game.play((1, 1), (2, 0)), MoveOutcome::Switch);
+        assert_eq!(game.target, Some((2, 0)));
+
+        // O must now play in the top-right sub-board
+        assert_eq!(game.play((1, 1), (0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 0), (0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((2, 0), (1, 1)), MoveOutcome::Switch);
+        assert_eq!(game.target, Some((1, 1)));
+    }
+
+    #[test]
+    fn test_invalid_plays() {
+        let mut game = UltimateState::new();
+
+        assert_eq!(game.play((3, 0), (0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 0), (0, 3)), MoveOutcome::NoChange);
+        assert_eq!(game.play((0, 0), (0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.play((0, 0), (0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.turn, Player::O);
+    }
+
+    #[test]
+    fn test_sub_board_win_claims_meta_cell() {
+        let mut game = UltimateState::new();
+
+        game.boards[0][0].values[0][0] = Some(Player::X);
+        game.boards[0][0].values[0][1] = Some(Player::X);
+        game.target = Some((0, 0));
+
+        assert_eq!(game.play((0, 0), (0, 2)), MoveOutcome::Switch);
+        assert_eq!(game.meta.values[0][0], Some(Player::X));
+        assert_eq!(game.target, Some((0, 2)));
+
+        // O points back at the won corner board, so X may play anywhere
+        assert_eq!(game.play((0, 2), (0, 0)), MoveOutcome::Switch);
+        assert_eq!(game.target, None);
+        assert!(!game.is_playable((0, 0)));
+        assert!(game.is_playable((2, 1)));
+        assert_eq!(game.play((0, 0), (1, 1)), MoveOutcome::NoChange);
+        assert_eq!(game.play((2, 1), (1, 1)), MoveOutcome::Switch);
+    }
+
+    #[test]
+    fn test_sent_to_full_board() {
+        let mut game = UltimateState::new();
+
+        //  X   O   X
+        //  X   X   O
+        //  O   X   O
+        game.boards[1][1] = Board {
+            values: [
+                [Some(Player::X), Some(Player::X), Some(Player::O)],
+                [Some(Player::O), Some(Player::X), Some(Player::X)],
+                [Some(Player::X), Some(Player::O), Some(Player::O)],
+            ],
+        };
+
+        assert!(!game.is_open((1, 1)));
+        assert_eq!(game.play((0, 0), (1, 1)), MoveOutcome::Switch);
+        assert_eq!(game.target, None);
+        assert_eq!(game.play((1, 1), (0, 0)), MoveOutcome::NoChange);
+        assert_eq!(game.play((2, 2), (0, 0)), MoveOutcome::Switch);
+    }
+
+    #[test]
+    fn test_meta_win() {
+        let mut game = UltimateState::new();
+
+        game.meta.values[0][0] = Some(Player::O);
+        game.meta.values[0][1] = Some(Player::O);
+        game.boards[0][2].values[0][0] = Some(Player::O);
+        game.boards[0][2].values[0][1] = Some(Player::O);
+        game.turn = Player::O;
+
+        assert_eq!(game.play((0, 2), (0, 2)), MoveOutcome::Win(Player::O));
+        assert_eq!(game.winner, Some(Player::O));
+        assert_eq!(game.log.wins(Player::O), 1);
+        assert_eq!(game.play((1, 1), (1, 1)), MoveOutcome::NoChange);
+    }
+
+    #[test]
+    fn test_meta_draw() {
+        let mut game = UltimateState::new();
+
+        //  X   O   X
+        //  X   O   O
+        //  O   X   .
+        game.meta = Board {
+            values: [
+                [Some(Player::X), Some(Player::X), Some(Player::O)],
+                [Some(Player::O), Some(Player::O), Some(Player::X)],
+                [Some(Player::X), Some(Player::O), None],
+            ],
+        };
+        game.boards[2][2] = Board {
+            values: [
+                [Some(Player::X), Some(Player::X), Some(Player::O)],
+                [Some(Player::O), Some(Player::X), Some(Player::X)],
+                [Some(Player::X), Some(Player::O), None],
+            ],
+        };
+
+        assert_eq!(game.play((2, 2), (2, 2)), MoveOutcome::Draw);
+        assert_eq!(game.log.draws, 1);
+    }
+}